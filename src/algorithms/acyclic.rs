@@ -0,0 +1,150 @@
+// 6.17 BFS
+
+use std::collections::{BTreeMap, VecDeque};
+
+use thiserror::Error;
+
+use crate::{
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+#[derive(Debug, Clone, Error)]
+pub enum IsAcyclicError {
+    #[error("Graph is undirected")]
+    UndirectedGraph,
+}
+
+pub fn is_acyclic<N: Node, W: Weight>(graph: &Graph<N, W>) -> Result<bool, IsAcyclicError> {
+    if !graph.directed {
+        return Err(IsAcyclicError::UndirectedGraph);
+    }
+
+    // В смешанном графе учитываются только дуги: рёбра всегда можно
+    // ориентировать по топологическому порядку дуг, не создав цикла. Каждой
+    // исходящей половине ребра соответствует входящая, их и вычитаем.
+    let mut nodes_in_degree: BTreeMap<N, usize> = graph
+        .nodes()
+        .map(|node| {
+            let ribs = graph.out_edges(node).filter(|e| e.rib).count();
+            (node.clone(), graph.in_degree(node) - ribs)
+        })
+        .collect();
+
+    let mut queue = VecDeque::new();
+    let mut visited_count: usize = 0;
+
+    for (node, in_degree) in nodes_in_degree.iter() {
+        if *in_degree == 0 {
+            queue.push_back(node.clone());
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        visited_count += 1;
+        for neighbour in graph.out_edges(&node).filter(|e| !e.rib) {
+            let in_degree = nodes_in_degree.get_mut(&neighbour.node).unwrap();
+            *in_degree -= 1;
+            if *in_degree == 0 {
+                queue.push_back(neighbour.node.clone());
+            }
+        }
+    }
+
+    Ok(visited_count == graph.edges.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use maplit::{btreemap, btreeset};
+
+    use crate::graph::Edge;
+
+    use super::*;
+
+    #[test]
+    fn test_is_acyclic_for_acyclic_graph() {
+        let graph: Graph<usize> = Graph::from_edges(
+            true,
+            btreemap! {
+                1 => btreeset! { Edge::value(3).build() },
+                2 => btreeset! { Edge::value(3).build() },
+                3 => btreeset! { Edge::value(4).build(), Edge::value(5).build() },
+                4 => btreeset! { Edge::value(5).build() },
+                5 => BTreeSet::new()
+            },
+        );
+
+        assert!(is_acyclic(&graph).unwrap());
+    }
+
+    #[test]
+    fn test_is_acyclic_for_cyclic_graph() {
+        let graph: Graph<usize> = Graph::from_edges(
+            true,
+            btreemap! {
+                0 => btreeset! { Edge::value(1).build() },
+                1 => btreeset! { Edge::value(2).build() },
+                2 => btreeset! { Edge::value(3).build() },
+                3 => btreeset! { Edge::value(1).build() },
+            },
+        );
+
+        assert!(!is_acyclic(&graph).unwrap());
+    }
+
+    #[test]
+    fn test_is_acyclic_isolated_nodes() {
+        let graph: Graph<usize> = Graph::from_edges(
+            true,
+            btreemap! {
+                0 => BTreeSet::new(),
+                1 => btreeset! { Edge::value(2).build() },
+                2 => btreeset! { Edge::value(3).build() },
+                3 => btreeset! { Edge::value(4).build() },
+                4 => btreeset! { Edge::value(2).build() },
+            },
+        );
+
+        assert!(!is_acyclic(&graph).unwrap());
+    }
+
+    #[test]
+    fn test_is_acyclic_two_components() {
+        let graph: Graph<usize> = Graph::from_edges(
+            true,
+            btreemap! {
+                1 => btreeset! { Edge::value(2).build(), Edge::value(3).build() },
+                2 => btreeset! { Edge::value(3).build() },
+                3 => BTreeSet::new(),
+                4 => btreeset! { Edge::value(5).build() },
+                5 => btreeset! { Edge::value(6).build() },
+                6 => btreeset! { Edge::value(4).build() },
+            },
+        );
+
+        assert!(!is_acyclic(&graph).unwrap());
+    }
+
+    #[test]
+    fn test_is_acyclic_mixed_graph() {
+        let mut graph = Graph::new_mixed();
+
+        for i in 1..=4 {
+            graph.add_node(i);
+        }
+
+        graph.add_edge(1, 2, 1, 1).unwrap();
+        graph.add_edge(2, 3, 1, 1).unwrap();
+        graph.add_rib(3, 4, 1, 1).unwrap();
+        graph.add_rib(4, 1, 1, 1).unwrap();
+
+        assert!(is_acyclic(&graph).unwrap());
+
+        graph.add_edge(3, 1, 1, 1).unwrap();
+
+        assert!(!is_acyclic(&graph).unwrap());
+    }
+}
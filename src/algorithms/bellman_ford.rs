@@ -0,0 +1,130 @@
+// Алгоритм Беллмана-Форда
+
+use std::{collections::BTreeMap, fmt::Debug};
+
+use itertools::process_results;
+use thiserror::Error;
+
+use crate::{
+    frozen::FrozenGraph,
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+#[derive(Debug, Clone, Error)]
+pub enum MinDistanceError<N: Debug> {
+    #[error("Negative cycle detected")]
+    NegativeCycle,
+
+    #[error("Empty graph")]
+    EmptyGraph,
+
+    #[error("Node {0:?} does not exist")]
+    NodeDoesNotExist(N),
+}
+
+pub fn min_distance<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+) -> Result<(N, W), MinDistanceError<N>> {
+    let results = graph
+        .nodes()
+        .map(|node| (node.clone(), bellman_ford(graph, node.clone())))
+        .map(|(node, distances)| {
+            distances.map(|d| (node, d.values().fold(W::zero(), |sum, &d| sum + d)))
+        });
+
+    process_results(results, |iter| {
+        iter.min_by(|(_, first), (_, second)| first.total_cmp(second))
+    })?
+    .ok_or(MinDistanceError::EmptyGraph)
+}
+
+pub fn bellman_ford<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    source: N,
+) -> Result<BTreeMap<N, W>, MinDistanceError<N>> {
+    let frozen = graph.freeze();
+    let Some(start) = frozen.index_of(&source) else {
+        return Err(MinDistanceError::NodeDoesNotExist(source));
+    };
+
+    let mut distances = vec![None; frozen.node_count()];
+    distances[start] = Some(W::zero());
+
+    for _ in 0..frozen.node_count() {
+        relax(&frozen, &mut distances);
+    }
+
+    if relax(&frozen, &mut distances) {
+        return Err(MinDistanceError::NegativeCycle);
+    }
+
+    Ok(frozen.translate(distances))
+}
+
+// Один проход по всем дугам; возвращает, изменилось ли хоть одно расстояние
+fn relax<N: Node, W: Weight>(frozen: &FrozenGraph<N, W>, distances: &mut [Option<W>]) -> bool {
+    let mut changed = false;
+
+    for arc in 0..frozen.arc_count() {
+        let Some(from_dist) = distances[frozen.source(arc)] else {
+            continue;
+        };
+
+        let new_dist = from_dist + frozen.weight(arc);
+        let to = frozen.target(arc);
+        if distances[to].is_none_or(|d| new_dist < d) {
+            distances[to] = Some(new_dist);
+            changed = true;
+        }
+    }
+
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bellman_ford() {
+        // Тестовые данные взяты из статьи:
+        // https://habr.com/ru/companies/otus/articles/484382/
+        let mut graph = Graph::new(true);
+
+        graph.add_node(0);
+        graph.add_node(1);
+        graph.add_node(2);
+        graph.add_node(3);
+        graph.add_node(4);
+        graph.add_node(5);
+
+        graph.add_edge(0, 1, -1, 1).unwrap();
+        graph.add_edge(0, 2, 4, 1).unwrap();
+        graph.add_edge(1, 2, 3, 1).unwrap();
+        graph.add_edge(1, 3, 2, 1).unwrap();
+        graph.add_edge(1, 4, 2, 1).unwrap();
+        graph.add_edge(3, 2, 5, 1).unwrap();
+        graph.add_edge(3, 1, 1, 1).unwrap();
+        graph.add_edge(4, 3, -3, 1).unwrap();
+
+        let result = bellman_ford(&graph, 0).unwrap();
+
+        assert_eq!(result[&0], 0);
+        assert_eq!(result[&1], -1);
+        assert_eq!(result[&2], 2);
+        assert_eq!(result[&3], -2);
+        assert_eq!(result[&4], 1);
+    }
+
+    #[test]
+    fn test_bellman_ford_missing_source() {
+        let mut graph: Graph<usize> = Graph::new(true);
+        graph.add_node(0);
+
+        assert!(matches!(
+            bellman_ford(&graph, 1),
+            Err(MinDistanceError::NodeDoesNotExist(1))
+        ));
+    }
+}
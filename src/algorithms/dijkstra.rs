@@ -0,0 +1,154 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    fmt::Debug,
+};
+
+use thiserror::Error;
+
+use crate::{
+    frozen::FrozenGraph,
+    graph::{Graph, Node},
+    weight::{Ordered, Weight},
+};
+
+#[derive(Debug, Clone, Error)]
+pub enum MinimalLengthToNodesError<N: Debug, W: Debug> {
+    #[error("Negative weight")]
+    NegativeWeight { from: N, to: N, weight: W },
+
+    #[error("Node {0:?} does not exist")]
+    NodeDoesNotExist(N),
+}
+
+// Алгоритм Дейкстры
+pub fn minimal_length_to_nodes<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    source: N,
+) -> Result<BTreeMap<N, W>, MinimalLengthToNodesError<N, W>> {
+    let frozen = graph.freeze();
+    let Some(start) = frozen.index_of(&source) else {
+        return Err(MinimalLengthToNodesError::NodeDoesNotExist(source));
+    };
+
+    let (distances, _) = dijkstra(&frozen, start)?;
+    Ok(frozen.translate(distances))
+}
+
+/// Дерево кратчайших путей из `source`: для каждой достижимой вершины,
+/// кроме самого источника, — предыдущая вершина на кратчайшем пути к ней
+pub fn shortest_path_tree<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    source: N,
+) -> Result<BTreeMap<N, N>, MinimalLengthToNodesError<N, W>> {
+    let frozen = graph.freeze();
+    let Some(start) = frozen.index_of(&source) else {
+        return Err(MinimalLengthToNodesError::NodeDoesNotExist(source));
+    };
+
+    let (_, parents) = dijkstra(&frozen, start)?;
+    let parents = parents
+        .into_iter()
+        .map(|parent| parent.map(|p| frozen.node(p).clone()))
+        .collect();
+    Ok(frozen.translate(parents))
+}
+
+// Расстояния от `start` и предыдущие вершины на кратчайших путях
+type Search<W> = (Vec<Option<W>>, Vec<Option<usize>>);
+
+fn dijkstra<N: Node, W: Weight>(
+    frozen: &FrozenGraph<N, W>,
+    start: usize,
+) -> Result<Search<W>, MinimalLengthToNodesError<N, W>> {
+    let mut distances = vec![None; frozen.node_count()];
+    let mut parents = vec![None; frozen.node_count()];
+    distances[start] = Some(W::zero());
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((Ordered(W::zero()), start)));
+
+    while let Some(Reverse((Ordered(distance), node))) = queue.pop() {
+        if distances[node].is_some_and(|d| distance > d) {
+            continue;
+        }
+
+        for arc in frozen.out_arcs(node) {
+            let (neighbour, weight) = (frozen.target(arc), frozen.weight(arc));
+            if weight < W::zero() {
+                return Err(MinimalLengthToNodesError::NegativeWeight {
+                    from: frozen.node(node).clone(),
+                    to: frozen.node(neighbour).clone(),
+                    weight,
+                });
+            }
+
+            let new_dist = distance.saturating_add(weight);
+            if distances[neighbour].is_none_or(|d| d > new_dist) {
+                distances[neighbour] = Some(new_dist);
+                parents[neighbour] = Some(node);
+                queue.push(Reverse((Ordered(new_dist), neighbour)));
+            }
+        }
+    }
+
+    Ok((distances, parents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_length_to_nodes_fractional_weights() {
+        let mut graph: Graph<char, f64> = Graph::new(true);
+
+        for node in 'A'..='D' {
+            graph.add_node(node);
+        }
+
+        graph.add_edge('A', 'B', 1.5, 1.0).unwrap();
+        graph.add_edge('A', 'C', 4.25, 1.0).unwrap();
+        graph.add_edge('B', 'C', 2.0, 1.0).unwrap();
+        graph.add_edge('C', 'D', 0.75, 1.0).unwrap();
+
+        let distances = minimal_length_to_nodes(&graph, 'A').unwrap();
+
+        assert_eq!(distances[&'A'], 0.0);
+        assert_eq!(distances[&'B'], 1.5);
+        assert_eq!(distances[&'C'], 3.5);
+        assert_eq!(distances[&'D'], 4.25);
+
+        let tree = shortest_path_tree(&graph, 'A').unwrap();
+        assert_eq!(tree, BTreeMap::from([('B', 'A'), ('C', 'B'), ('D', 'C')]));
+    }
+
+    #[test]
+    fn test_minimal_length_to_nodes_negative_weight() {
+        let mut graph: Graph<usize, i64> = Graph::new(true);
+
+        graph.add_node(0);
+        graph.add_node(1);
+        graph.add_edge(0, 1, -2, 1).unwrap();
+
+        assert!(matches!(
+            minimal_length_to_nodes(&graph, 0),
+            Err(MinimalLengthToNodesError::NegativeWeight { weight: -2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_minimal_length_to_nodes_missing_source() {
+        let mut graph: Graph<usize, i64> = Graph::new(true);
+        graph.add_node(0);
+
+        assert!(matches!(
+            minimal_length_to_nodes(&graph, 1),
+            Err(MinimalLengthToNodesError::NodeDoesNotExist(1))
+        ));
+        assert!(matches!(
+            shortest_path_tree(&graph, 1),
+            Err(MinimalLengthToNodesError::NodeDoesNotExist(1))
+        ));
+    }
+}
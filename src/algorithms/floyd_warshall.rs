@@ -0,0 +1,61 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+pub fn n_periphery<N: Node, W: Weight>(graph: &Graph<N, W>, target: N, n: W) -> BTreeSet<N> {
+    floyd_warshall(graph)
+        .into_iter()
+        .filter_map(|(from, to, distance)| {
+            (to == target && distance.is_none_or(|d| d > n)).then_some(from)
+        })
+        .collect()
+}
+
+pub fn floyd_warshall<N: Node, W: Weight>(graph: &Graph<N, W>) -> Vec<(N, N, Option<W>)> {
+    let frozen = graph.freeze();
+    let n = frozen.node_count();
+
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // None обозначает отсутствие пути
+    let mut dist: Vec<Vec<Option<W>>> = vec![vec![None; n]; n];
+
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = Some(W::zero());
+    }
+
+    for arc in 0..frozen.arc_count() {
+        let (i, j, weight) = (frozen.source(arc), frozen.target(arc), frozen.weight(arc));
+        // Из параллельных дуг выбирается самая короткая
+        if dist[i][j].is_none_or(|d| weight < d) {
+            dist[i][j] = Some(weight);
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if let (Some(first), Some(second)) = (dist[i][k], dist[k][j]) {
+                    let new_dist = first + second;
+                    if dist[i][j].is_none_or(|d| new_dist < d) {
+                        dist[i][j] = Some(new_dist);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut result = Vec::with_capacity(n * n);
+    for (i, row) in dist.iter().enumerate() {
+        for (j, distance) in row.iter().enumerate() {
+            result.push((frozen.node(i).clone(), frozen.node(j).clone(), *distance));
+        }
+    }
+
+    result
+}
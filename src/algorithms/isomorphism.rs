@@ -0,0 +1,375 @@
+// Изоморфизм графов в духе VF2: вершины сопоставляются по одной, каждое
+// новое сопоставление проверяется на согласованность с уже сделанными
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Что, кроме структуры графа, должно совпадать у сопоставленных вершин и
+/// рёбер. По умолчанию сравнивается только структура.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Compare {
+    /// Веса и пропускные способности рёбер
+    pub weights: bool,
+    /// Свойства вершин и рёбер
+    pub attributes: bool,
+}
+
+/// Ищет изоморфизм графов и возвращает соответствие вершин первого графа
+/// вершинам второго. Учитываются направление дуг, петли, кратные рёбра и,
+/// если указано в `compare`, веса и свойства.
+pub fn isomorphism<A: Node, B: Node, W: Weight>(
+    first: &Graph<A, W>,
+    second: &Graph<B, W>,
+    compare: Compare,
+) -> Option<BTreeMap<A, B>> {
+    if first.directed != second.directed
+        || first.edges.len() != second.edges.len()
+        || first.arcs().count() != second.arcs().count()
+    {
+        return None;
+    }
+
+    let (pattern, target) = (Indexed::new(first), Indexed::new(second));
+    if pattern.degree_sequence() != target.degree_sequence() {
+        return None;
+    }
+
+    Matcher::new(&pattern, &target, compare, true).run()
+}
+
+/// Изоморфны ли графы
+pub fn is_isomorphic<A: Node, B: Node, W: Weight>(
+    first: &Graph<A, W>,
+    second: &Graph<B, W>,
+    compare: Compare,
+) -> bool {
+    isomorphism(first, second, compare).is_some()
+}
+
+/// Ищет в `target` подграф, изоморфный `pattern`, и возвращает вложение
+/// вершин образца. Подграф не обязан быть порождённым: между образами
+/// несмежных вершин образца в `target` могут быть рёбра.
+pub fn subgraph_isomorphism<A: Node, B: Node, W: Weight>(
+    pattern: &Graph<A, W>,
+    target: &Graph<B, W>,
+    compare: Compare,
+) -> Option<BTreeMap<A, B>> {
+    if pattern.directed != target.directed || pattern.edges.len() > target.edges.len() {
+        return None;
+    }
+
+    let (pattern, target) = (Indexed::new(pattern), Indexed::new(target));
+    Matcher::new(&pattern, &target, compare, false).run()
+}
+
+// Граф с вершинами, пронумерованными по порядку
+struct Indexed<'a, N, W: Weight> {
+    nodes: Vec<&'a N>,
+    links: BTreeMap<(usize, usize), Vec<&'a Edge<N, W>>>,
+    successors: Vec<BTreeSet<usize>>,
+    predecessors: Vec<BTreeSet<usize>>,
+    out_degree: Vec<usize>,
+    in_degree: Vec<usize>,
+    attributes: Vec<Option<&'a Attributes>>,
+}
+
+impl<'a, N: Node, W: Weight> Indexed<'a, N, W> {
+    fn new(graph: &'a Graph<N, W>) -> Self {
+        let nodes: Vec<&N> = graph.nodes().collect();
+        let index: BTreeMap<&N, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+
+        let mut links: BTreeMap<(usize, usize), Vec<&Edge<N, W>>> = BTreeMap::new();
+        let mut successors = vec![BTreeSet::new(); nodes.len()];
+        let mut predecessors = vec![BTreeSet::new(); nodes.len()];
+        for (from, to, edge) in graph.arcs() {
+            let (from, to) = (index[from], index[to]);
+            links.entry((from, to)).or_default().push(edge);
+            successors[from].insert(to);
+            predecessors[to].insert(from);
+        }
+
+        Indexed {
+            out_degree: nodes.iter().map(|n| graph.out_degree(n)).collect(),
+            in_degree: nodes.iter().map(|n| graph.in_degree(n)).collect(),
+            attributes: nodes.iter().map(|n| graph.node_attributes.get(n)).collect(),
+            nodes,
+            links,
+            successors,
+            predecessors,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn links(&self, from: usize, to: usize) -> &[&'a Edge<N, W>] {
+        self.links.get(&(from, to)).map_or(&[], Vec::as_slice)
+    }
+
+    fn degree_sequence(&self) -> Vec<(usize, usize)> {
+        let mut degrees: Vec<_> = self
+            .out_degree
+            .iter()
+            .copied()
+            .zip(self.in_degree.clone())
+            .collect();
+        degrees.sort();
+        degrees
+    }
+}
+
+// Вершина образца в порядке сопоставления и уже сопоставленный сосед, среди
+// образов которого ищутся кандидаты (`true` — сосед является началом дуги)
+type Step = (usize, Option<(usize, bool)>);
+
+struct Matcher<'p, 't, 'a, 'b, A, B, W: Weight> {
+    pattern: &'p Indexed<'a, A, W>,
+    target: &'t Indexed<'b, B, W>,
+    compare: Compare,
+    // Полное совпадение (изоморфизм) или вложение (подграф)
+    exact: bool,
+    order: Vec<Step>,
+    core: Vec<Option<usize>>,
+    used: Vec<bool>,
+}
+
+impl<'p, 't, 'a, 'b, A: Node, B: Node, W: Weight> Matcher<'p, 't, 'a, 'b, A, B, W> {
+    fn new(
+        pattern: &'p Indexed<'a, A, W>,
+        target: &'t Indexed<'b, B, W>,
+        compare: Compare,
+        exact: bool,
+    ) -> Self {
+        Matcher {
+            order: matching_order(pattern),
+            core: vec![None; pattern.len()],
+            used: vec![false; target.len()],
+            pattern,
+            target,
+            compare,
+            exact,
+        }
+    }
+
+    fn run(mut self) -> Option<BTreeMap<A, B>> {
+        if !self.extend(0) {
+            return None;
+        }
+
+        Some(
+            self.core
+                .iter()
+                .enumerate()
+                .map(|(u, v)| {
+                    let v = v.expect("every pattern node is matched");
+                    (self.pattern.nodes[u].clone(), self.target.nodes[v].clone())
+                })
+                .collect(),
+        )
+    }
+
+    fn extend(&mut self, depth: usize) -> bool {
+        let Some(&(u, parent)) = self.order.get(depth) else {
+            return true;
+        };
+
+        let candidates: Vec<usize> = match parent {
+            Some((p, true)) => {
+                let image = self.core[p].unwrap();
+                self.target.successors[image].iter().copied().collect()
+            }
+            Some((p, false)) => {
+                let image = self.core[p].unwrap();
+                self.target.predecessors[image].iter().copied().collect()
+            }
+            None => (0..self.target.len()).collect(),
+        };
+
+        for v in candidates {
+            if self.used[v] || !self.feasible(depth, u, v) {
+                continue;
+            }
+
+            self.core[u] = Some(v);
+            self.used[v] = true;
+            if self.extend(depth + 1) {
+                return true;
+            }
+            self.core[u] = None;
+            self.used[v] = false;
+        }
+
+        false
+    }
+
+    fn feasible(&self, depth: usize, u: usize, v: usize) -> bool {
+        let (p, t) = (self.pattern, self.target);
+        let degrees_fit = if self.exact {
+            p.out_degree[u] == t.out_degree[v] && p.in_degree[u] == t.in_degree[v]
+        } else {
+            p.out_degree[u] <= t.out_degree[v] && p.in_degree[u] <= t.in_degree[v]
+        };
+        if !degrees_fit {
+            return false;
+        }
+
+        if self.compare.attributes && !same_attributes(p.attributes[u], t.attributes[v]) {
+            return false;
+        }
+
+        if !self.links_fit(p.links(u, u), t.links(v, v)) {
+            return false;
+        }
+
+        self.order[..depth].iter().all(|&(m, _)| {
+            let image = self.core[m].unwrap();
+            self.links_fit(p.links(u, m), t.links(v, image))
+                && self.links_fit(p.links(m, u), t.links(image, v))
+        })
+    }
+
+    // Рёбра образца между парой вершин сопоставляются рёбрам цели между их
+    // образами: при изоморфизме все, при вложении хотя бы для каждого ребра
+    // образца найдётся своё ребро цели
+    fn links_fit(&self, pattern: &[&Edge<A, W>], target: &[&Edge<B, W>]) -> bool {
+        if pattern.len() > target.len() || (self.exact && pattern.len() != target.len()) {
+            return false;
+        }
+
+        let mut taken = vec![false; target.len()];
+        pattern.iter().all(|e| {
+            let found = target
+                .iter()
+                .enumerate()
+                .find(|&(i, f)| !taken[i] && self.same_edge(e, f));
+            match found {
+                Some((i, _)) => {
+                    taken[i] = true;
+                    true
+                }
+                None => false,
+            }
+        })
+    }
+
+    fn same_edge(&self, e: &Edge<A, W>, f: &Edge<B, W>) -> bool {
+        e.rib == f.rib
+            && (!self.compare.weights
+                || (e.weight.total_cmp(&f.weight) == Ordering::Equal
+                    && e.capacity.total_cmp(&f.capacity) == Ordering::Equal))
+            && (!self.compare.attributes || e.attributes == f.attributes)
+    }
+}
+
+fn same_attributes(left: Option<&Attributes>, right: Option<&Attributes>) -> bool {
+    left.filter(|a| !a.is_empty()) == right.filter(|a| !a.is_empty())
+}
+
+// Порядок сопоставления: сначала вершина наибольшей степени, затем каждый раз
+// вершина с наибольшим числом связей с уже выбранными. Так каждая следующая
+// вершина связной компоненты ищется только среди соседей образа.
+fn matching_order<N: Node, W: Weight>(graph: &Indexed<N, W>) -> Vec<Step> {
+    let n = graph.len();
+    let degree = |u: usize| graph.out_degree[u] + graph.in_degree[u];
+    let mut order: Vec<Step> = Vec::with_capacity(n);
+    let mut chosen = vec![false; n];
+
+    while order.len() < n {
+        let next = (0..n).filter(|&u| !chosen[u]).max_by_key(|&u| {
+            let connections = order
+                .iter()
+                .filter(|&&(m, _)| {
+                    graph.successors[m].contains(&u) || graph.predecessors[m].contains(&u)
+                })
+                .count();
+            (connections, degree(u), std::cmp::Reverse(u))
+        });
+        let u = next.unwrap();
+
+        let parent = order.iter().find_map(|&(m, _)| {
+            if graph.successors[m].contains(&u) {
+                Some((m, true))
+            } else if graph.predecessors[m].contains(&u) {
+                Some((m, false))
+            } else {
+                None
+            }
+        });
+
+        chosen[u] = true;
+        order.push((u, parent));
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle(directed: bool, labels: &[char]) -> Graph<char> {
+        let mut graph = Graph::new(directed);
+        for &label in labels {
+            graph.add_node(label);
+        }
+        for (i, &from) in labels.iter().enumerate() {
+            let to = labels[(i + 1) % labels.len()];
+            if directed {
+                graph.add_edge(from, to, i as i32 + 1, 1).unwrap();
+            } else {
+                graph.add_rib(from, to, i as i32 + 1, 1).unwrap();
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_isomorphism_returns_mapping() {
+        let first = cycle(true, &['A', 'B', 'C', 'D']);
+        let second = cycle(true, &['W', 'Y', 'X', 'Z']);
+
+        let mapping = isomorphism(&first, &second, Compare::default()).unwrap();
+        for (from, to, _) in first.arcs() {
+            assert!(second.contains_edge(&mapping[from], &mapping[to]));
+        }
+
+        // Веса у второго цикла сдвинуты, поэтому с учётом весов A -> W
+        let weighted = Compare {
+            weights: true,
+            ..Compare::default()
+        };
+        assert_eq!(isomorphism(&first, &second, weighted).unwrap()[&'A'], 'W');
+
+        let mut reversed = first.reversed();
+        assert!(is_isomorphic(&first, &reversed, Compare::default()));
+        reversed.add_edge('A', 'C', 1, 1).unwrap();
+        assert!(!is_isomorphic(&first, &reversed, Compare::default()));
+        assert!(!is_isomorphic(
+            &first,
+            &cycle(false, &['A', 'B', 'C', 'D']),
+            Compare::default()
+        ));
+    }
+
+    #[test]
+    fn test_subgraph_isomorphism() {
+        let triangle = cycle(false, &['a', 'b', 'c']);
+        let mut square = cycle(false, &['A', 'B', 'C', 'D']);
+
+        assert!(subgraph_isomorphism(&triangle, &square, Compare::default()).is_none());
+
+        square.add_rib('A', 'C', 1, 1).unwrap();
+        let mapping = subgraph_isomorphism(&triangle, &square, Compare::default()).unwrap();
+        let images: BTreeSet<char> = mapping.values().copied().collect();
+        assert!(images.contains(&'A') && images.contains(&'C'));
+        assert!(!is_isomorphic(&triangle, &square, Compare::default()));
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::{
+    frozen::FrozenGraph,
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+// Шаг увеличивающегося пути: по дуге вперёд или против неё (отмена потока)
+#[derive(Clone, Copy)]
+struct Step {
+    arc: usize,
+    forward: bool,
+}
+
+/// Максимальный поток и его распределение по дугам
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaxFlow<N, W> {
+    pub value: W,
+    /// Поток по каждой паре вершин; параллельные дуги складываются
+    pub flows: BTreeMap<(N, N), W>,
+    /// Дуги, поток по которым равен пропускной способности
+    pub saturated: BTreeSet<(N, N)>,
+}
+
+pub fn ford_fulkerson<N: Node, W: Weight>(graph: &Graph<N, W>, source: N, sink: N) -> W {
+    max_flow(graph, source, sink).value
+}
+
+/// Алгоритм Форда-Фалкерсона (Эдмондса-Карпа) с потоком по каждой дуге
+pub fn max_flow<N: Node, W: Weight>(graph: &Graph<N, W>, source: N, sink: N) -> MaxFlow<N, W> {
+    let frozen = graph.freeze();
+    let (Some(source), Some(sink)) = (frozen.index_of(&source), frozen.index_of(&sink)) else {
+        return MaxFlow {
+            value: W::zero(),
+            flows: BTreeMap::new(),
+            saturated: BTreeSet::new(),
+        };
+    };
+
+    // Поток хранится отдельно для каждой дуги, поэтому параллельные дуги
+    // складывают свои пропускные способности
+    let mut flow = vec![W::zero(); frozen.arc_count()];
+    let mut max_flow = W::zero();
+
+    loop {
+        // Поиск увеличивающегося пути при помощи BFS
+        let parent = bfs(&frozen, source, sink, &flow);
+
+        // Если путь до стока не найден - завершаем
+        if source == sink || parent[sink].is_none() {
+            break;
+        }
+
+        // Находим минимальную остаточную пропускную способность
+        let mut path_flow = W::infinity();
+        let mut v = sink;
+        while v != source {
+            let step = parent[v].unwrap();
+            let residual = get_residual_capacity(&frozen, &flow, step);
+            if residual < path_flow {
+                path_flow = residual;
+            }
+            v = from_node(&frozen, step);
+        }
+
+        // Обновляем поток вдоль найденного пути
+        v = sink;
+        while v != source {
+            let step = parent[v].unwrap();
+            if step.forward {
+                flow[step.arc] = flow[step.arc] + path_flow;
+            } else {
+                flow[step.arc] = flow[step.arc] - path_flow;
+            }
+            v = from_node(&frozen, step);
+        }
+
+        max_flow = max_flow + path_flow;
+    }
+
+    let mut flows = BTreeMap::new();
+    let mut saturated = BTreeSet::new();
+    for (arc, &amount) in flow.iter().enumerate() {
+        if amount <= W::zero() {
+            continue;
+        }
+
+        let key = (
+            frozen.node(frozen.source(arc)).clone(),
+            frozen.node(frozen.target(arc)).clone(),
+        );
+        if amount >= frozen.capacity(arc) {
+            saturated.insert(key.clone());
+        }
+        let total = flows.entry(key).or_insert(W::zero());
+        *total = *total + amount;
+    }
+
+    MaxFlow {
+        value: max_flow,
+        flows,
+        saturated,
+    }
+}
+
+fn bfs<N: Node, W: Weight>(
+    frozen: &FrozenGraph<N, W>,
+    source: usize,
+    sink: usize,
+    flow: &[W],
+) -> Vec<Option<Step>> {
+    let mut parent = vec![None; frozen.node_count()];
+    let mut visited = vec![false; frozen.node_count()];
+    let mut queue = VecDeque::new();
+
+    visited[source] = true;
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        let forward = frozen.out_arcs(node).map(|arc| Step { arc, forward: true });
+        let backward = frozen.in_arcs(node).iter().map(|&arc| Step {
+            arc,
+            forward: false,
+        });
+
+        for step in forward.chain(backward) {
+            let neighbour = to_node(frozen, step);
+            let residual = get_residual_capacity(frozen, flow, step);
+
+            if !visited[neighbour] && residual > W::zero() {
+                visited[neighbour] = true;
+                parent[neighbour] = Some(step);
+                queue.push_back(neighbour);
+
+                // Если мы достигли стока, это значит, что путь найден
+                if neighbour == sink {
+                    return parent;
+                }
+            }
+        }
+    }
+
+    parent
+}
+
+fn get_residual_capacity<N: Node, W: Weight>(
+    frozen: &FrozenGraph<N, W>,
+    flow: &[W],
+    step: Step,
+) -> W {
+    if step.forward {
+        frozen.capacity(step.arc) - flow[step.arc]
+    } else {
+        flow[step.arc]
+    }
+}
+
+fn from_node<N: Node, W: Weight>(frozen: &FrozenGraph<N, W>, step: Step) -> usize {
+    if step.forward {
+        frozen.source(step.arc)
+    } else {
+        frozen.target(step.arc)
+    }
+}
+
+fn to_node<N: Node, W: Weight>(frozen: &FrozenGraph<N, W>, step: Step) -> usize {
+    if step.forward {
+        frozen.target(step.arc)
+    } else {
+        frozen.source(step.arc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ford_fulkerson_max_flow_10() {
+        // Граф взят из статьи: https://habr.com/ru/articles/566248/
+        let mut graph = Graph::new(true);
+
+        // A=0, B=1, C=2, D=3, E=4, F=5
+        for i in 0..=5 {
+            graph.add_node(i);
+        }
+
+        graph.add_edge(0, 1, 1, 7).unwrap();
+        graph.add_edge(0, 2, 1, 4).unwrap();
+        graph.add_edge(1, 2, 1, 4).unwrap();
+        graph.add_edge(1, 4, 1, 2).unwrap();
+        graph.add_edge(2, 3, 1, 4).unwrap();
+        graph.add_edge(2, 4, 1, 8).unwrap();
+        graph.add_edge(3, 5, 1, 12).unwrap();
+        graph.add_edge(4, 3, 1, 4).unwrap();
+        graph.add_edge(4, 5, 1, 5).unwrap();
+
+        let result = max_flow(&graph, 0, 5);
+        assert_eq!(result.value, 10);
+        assert_eq!(result.flows[&(0, 1)], 6);
+        assert!(result.saturated.contains(&(0, 2)) && result.saturated.contains(&(1, 4)));
+        assert!(!result.saturated.contains(&(3, 5)));
+    }
+
+    #[test]
+    fn test_ford_fulkerson_with_letter_nodes() {
+        let mut graph = Graph::new(true);
+
+        for node in 'A'..='F' {
+            graph.add_node(node);
+        }
+
+        graph.add_edge('A', 'B', 1, 7).unwrap();
+        graph.add_edge('A', 'C', 1, 4).unwrap();
+        graph.add_edge('B', 'C', 1, 4).unwrap();
+        graph.add_edge('B', 'E', 1, 2).unwrap();
+        graph.add_edge('C', 'D', 1, 4).unwrap();
+        graph.add_edge('C', 'E', 1, 8).unwrap();
+        graph.add_edge('D', 'F', 1, 12).unwrap();
+        graph.add_edge('E', 'D', 1, 4).unwrap();
+        graph.add_edge('E', 'F', 1, 5).unwrap();
+
+        assert_eq!(ford_fulkerson(&graph, 'A', 'F'), 10);
+    }
+
+    #[test]
+    fn test_ford_fulkerson_parallel_arcs() {
+        let mut graph = Graph::new_multigraph(true);
+
+        for i in 0..=2 {
+            graph.add_node(i);
+        }
+
+        graph.add_edge(0, 1, 1, 3).unwrap();
+        graph.add_edge(0, 1, 1, 4).unwrap();
+        graph.add_edge(1, 2, 1, 10).unwrap();
+
+        assert_eq!(ford_fulkerson(&graph, 0, 2), 7);
+    }
+}
@@ -0,0 +1,12 @@
+pub mod acyclic;
+pub mod bellman_ford;
+pub mod dijkstra;
+pub mod floyd_warshall;
+pub mod isomorphism;
+pub mod max_flow;
+pub mod mst;
+pub mod neighbours;
+pub mod outdegree;
+pub mod products;
+pub mod scc;
+pub mod set_ops;
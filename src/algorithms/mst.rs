@@ -0,0 +1,130 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
+    fmt::Debug,
+};
+
+use thiserror::Error;
+
+use crate::{
+    graph::{Graph, Node},
+    weight::{Ordered, Weight},
+};
+
+#[derive(Debug, Clone, Error)]
+pub enum MstPrimError<N: Debug> {
+    #[error("Start node {0:?} does not exist")]
+    StartNodeDoesNotExist(N),
+
+    #[error("Graph must be undirected")]
+    DirectedGraph,
+}
+
+pub fn mst_prim<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    start_node: N,
+) -> Result<Graph<N, W>, MstPrimError<N>> {
+    if graph.directed {
+        return Err(MstPrimError::DirectedGraph);
+    }
+
+    if !graph.contains_node(&start_node) {
+        return Err(MstPrimError::StartNodeDoesNotExist(start_node));
+    }
+
+    let mut visited = BTreeSet::new();
+    let mut mst = Graph::new(false);
+    let mut queue = BinaryHeap::new();
+
+    visited.insert(start_node.clone());
+    for neighbour in graph.out_edges(&start_node) {
+        queue.push(Reverse((
+            Ordered(neighbour.weight),
+            start_node.clone(),
+            neighbour.node.clone(),
+        )));
+    }
+
+    while let Some(Reverse((Ordered(weight), from, to))) = queue.pop()
+        && visited.len() < graph.edges.len()
+    {
+        if visited.contains(&to) {
+            continue;
+        }
+
+        mst.add_node(from.clone());
+        mst.add_node(to.clone());
+        mst.add_rib(from, to.clone(), weight, W::one()).unwrap();
+        visited.insert(to.clone());
+
+        for neighbour in graph.out_edges(&to) {
+            if !visited.contains(&neighbour.node) {
+                queue.push(Reverse((
+                    Ordered(neighbour.weight),
+                    to.clone(),
+                    neighbour.node.clone(),
+                )));
+            }
+        }
+    }
+
+    Ok(mst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mst_prim() {
+        let mut graph = Graph::new(false);
+
+        for i in 1..=6 {
+            graph.add_node(i);
+        }
+
+        graph.add_rib(1, 2, 4, 1).unwrap();
+        graph.add_rib(1, 3, 2, 1).unwrap();
+        graph.add_rib(2, 3, 1, 1).unwrap();
+        graph.add_rib(2, 4, 5, 1).unwrap();
+        graph.add_rib(3, 4, 8, 1).unwrap();
+        graph.add_rib(3, 5, 10, 1).unwrap();
+        graph.add_rib(4, 5, 2, 1).unwrap();
+        graph.add_rib(4, 6, 6, 1).unwrap();
+        graph.add_rib(5, 6, 3, 1).unwrap();
+
+        let mut expected_mst = Graph::new(false);
+        for i in 1..=6 {
+            expected_mst.add_node(i);
+        }
+        expected_mst.add_rib(1, 3, 2, 1).unwrap();
+        expected_mst.add_rib(3, 2, 1, 1).unwrap();
+        expected_mst.add_rib(2, 4, 5, 1).unwrap();
+        expected_mst.add_rib(4, 5, 2, 1).unwrap();
+        expected_mst.add_rib(5, 6, 3, 1).unwrap();
+
+        let actual_mst = mst_prim(&graph, 1).unwrap();
+
+        assert_eq!(expected_mst.edges, actual_mst.edges);
+    }
+
+    #[test]
+    fn test_mst_prim_parallel_ribs() {
+        let mut graph = Graph::new_multigraph(false);
+
+        for i in 1..=3 {
+            graph.add_node(i);
+        }
+
+        graph.add_rib(1, 2, 7, 1).unwrap();
+        graph.add_rib(1, 2, 2, 1).unwrap();
+        graph.add_rib(2, 3, 4, 1).unwrap();
+        graph.add_rib(2, 3, 9, 1).unwrap();
+
+        let mst = mst_prim(&graph, 1).unwrap();
+
+        let weights: Vec<i32> = mst.edges[&2].iter().map(|e| e.weight).collect();
+        assert_eq!(weights.len(), 2);
+        assert!(weights.contains(&2) && weights.contains(&4));
+    }
+}
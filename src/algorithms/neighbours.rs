@@ -0,0 +1,56 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use crate::{
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+#[derive(Clone, Debug, Error)]
+pub enum OutgoingNodesError<N: Debug> {
+    #[error("Node {0:?} does not exist")]
+    NodeDoesNotExist(N),
+
+    #[error("Graph is undirected")]
+    UndirectedGraph,
+}
+
+pub fn get_outgoing_nodes<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    node: N,
+) -> Result<Vec<N>, OutgoingNodesError<N>> {
+    if !graph.directed {
+        return Err(OutgoingNodesError::UndirectedGraph);
+    }
+
+    if !graph.contains_node(&node) {
+        return Err(OutgoingNodesError::NodeDoesNotExist(node));
+    }
+
+    Ok(graph.successors(&node).cloned().collect())
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum IncomingNodesError<N: Debug> {
+    #[error("Node {0:?} does not exist")]
+    NodeDoesNotExist(N),
+
+    #[error("Graph is undirected")]
+    UndirectedGraph,
+}
+
+pub fn get_incoming_nodes<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    node: N,
+) -> Result<Vec<N>, IncomingNodesError<N>> {
+    if !graph.directed {
+        return Err(IncomingNodesError::UndirectedGraph);
+    }
+
+    if !graph.contains_node(&node) {
+        return Err(IncomingNodesError::NodeDoesNotExist(node));
+    }
+
+    Ok(graph.predecessors(&node).cloned().collect())
+}
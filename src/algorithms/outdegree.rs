@@ -0,0 +1,58 @@
+use std::{collections::BTreeSet, fmt::Debug};
+
+use thiserror::Error;
+
+use crate::{
+    algorithms::neighbours::{OutgoingNodesError, get_outgoing_nodes},
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+#[derive(Clone, Debug, Error)]
+pub enum NodesWithGreaterOutdegreeError<N: Debug> {
+    #[error("Node {0:?} does not exist")]
+    NodeDoesNotExist(N),
+
+    #[error("Graph is undirected")]
+    UndirectedGraph,
+}
+
+pub fn get_nodes_with_greater_outdegree<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    node: N,
+) -> Result<BTreeSet<N>, NodesWithGreaterOutdegreeError<N>> {
+    match get_outgoing_nodes(graph, node.clone()) {
+        Ok(outgoing_nodes) => Ok(graph
+            .nodes()
+            .filter(|k| **k != node && graph.out_degree(k) > outgoing_nodes.len())
+            .cloned()
+            .collect()),
+        Err(e) => match e {
+            OutgoingNodesError::NodeDoesNotExist(node) => {
+                Err(NodesWithGreaterOutdegreeError::NodeDoesNotExist(node))
+            }
+            OutgoingNodesError::UndirectedGraph => {
+                Err(NodesWithGreaterOutdegreeError::UndirectedGraph)
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compares_outdegree_of_each_node() {
+        let mut graph = Graph::new(true);
+        for node in 0..4 {
+            graph.add_node(node);
+        }
+        graph.add_edge(0, 1, 1, 1).unwrap();
+        graph.add_edge(2, 0, 1, 1).unwrap();
+        graph.add_edge(2, 1, 1, 1).unwrap();
+
+        let nodes = get_nodes_with_greater_outdegree(&graph, 0).unwrap();
+        assert_eq!(nodes, BTreeSet::from([2]));
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use thiserror::Error;
+
+use crate::{
+    graph::{Edge, Graph, Node},
+    weight::{Weight, WeightPolicy},
+};
+
+#[derive(Clone, Debug, Error)]
+pub enum ProductError {
+    #[error("One graph is directed and the other is undirected")]
+    DirectednessMismatch,
+
+    #[error("Mixed graphs are not supported")]
+    MixedGraph,
+}
+
+/// Вид произведения графов
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Product {
+    /// `(u, v) ~ (u', v)` при `u ~ u'` и `(u, v) ~ (u, v')` при `v ~ v'`
+    Cartesian,
+    /// `(u, v) ~ (u', v')` при `u ~ u'` и `v ~ v'`
+    Tensor,
+    /// Объединение декартова и тензорного произведений
+    Strong,
+    /// `(u, v) ~ (u', v')` при `u ~ u'` или при `u = u'` и `v ~ v'`
+    Lexicographic,
+}
+
+type Lists<N, W> = BTreeMap<N, BTreeSet<Edge<N, W>>>;
+
+// Ребро исходного графа как вершина рёберного графа
+type Link<'a, N, W> = ((N, N, usize), &'a Edge<N, W>);
+
+/// Произведение графов. Вершины результата — пары вершин операндов. Если
+/// ребро результата порождено рёбрами обоих графов, его вес и пропускная
+/// способность объединяются по `policy`, иначе берутся из единственного ребра.
+/// Параллельные рёбра операндов не различаются.
+pub fn product<A: Node, B: Node, W: Weight>(
+    first: &Graph<A, W>,
+    second: &Graph<B, W>,
+    kind: Product,
+    policy: WeightPolicy,
+) -> Result<Graph<(A, B), W>, ProductError> {
+    if first.mixed || second.mixed {
+        return Err(ProductError::MixedGraph);
+    }
+    if first.directed != second.directed {
+        return Err(ProductError::DirectednessMismatch);
+    }
+
+    let mut edges: Lists<(A, B), W> = BTreeMap::new();
+    for u in first.edges.keys() {
+        for v in second.edges.keys() {
+            edges.insert((u.clone(), v.clone()), BTreeSet::new());
+        }
+    }
+
+    let cartesian = matches!(kind, Product::Cartesian | Product::Strong);
+    let tensor = matches!(kind, Product::Tensor | Product::Strong);
+
+    for (u, first_edges) in &first.edges {
+        for e in first_edges {
+            for v in second.edges.keys() {
+                if cartesian {
+                    insert(
+                        &mut edges,
+                        (u.clone(), v.clone()),
+                        (e.node.clone(), v.clone()),
+                        e.weight,
+                        e.capacity,
+                    );
+                }
+                if kind == Product::Lexicographic {
+                    for v2 in second.edges.keys() {
+                        insert(
+                            &mut edges,
+                            (u.clone(), v.clone()),
+                            (e.node.clone(), v2.clone()),
+                            e.weight,
+                            e.capacity,
+                        );
+                    }
+                }
+                if tensor {
+                    for f in &second.edges[v] {
+                        insert(
+                            &mut edges,
+                            (u.clone(), v.clone()),
+                            (e.node.clone(), f.node.clone()),
+                            policy.merge(e.weight, f.weight),
+                            policy.merge(e.capacity, f.capacity),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    if kind != Product::Tensor {
+        for u in first.edges.keys() {
+            for (v, second_edges) in &second.edges {
+                for f in second_edges {
+                    insert(
+                        &mut edges,
+                        (u.clone(), v.clone()),
+                        (u.clone(), f.node.clone()),
+                        f.weight,
+                        f.capacity,
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(Graph::from_edges(first.directed, edges))
+}
+
+/// Рёберный граф. Вершины — рёбра исходного графа в виде `(начало, конец,
+/// идентификатор)`, у рёбер неориентированного графа начало не больше конца.
+/// Вершины соединены, если рёбра имеют общий конец (для дуг — если вторая
+/// начинается там, где кончается первая). Веса объединяются по `policy`,
+/// свойства рёбер становятся свойствами вершин.
+pub fn line_graph<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    policy: WeightPolicy,
+) -> Result<Graph<(N, N, usize), W>, ProductError> {
+    if graph.mixed {
+        return Err(ProductError::MixedGraph);
+    }
+
+    let links: Vec<Link<N, W>> = graph
+        .arcs()
+        .filter(|(from, to, _)| graph.directed || from <= to)
+        .map(|(from, to, edge)| ((from.clone(), to.clone(), edge.id), edge))
+        .collect();
+
+    let mut result = Graph::new(graph.directed);
+    for (key, edge) in &links {
+        result.add_node(key.clone());
+        if !edge.attributes.is_empty() {
+            result
+                .node_attributes
+                .insert(key.clone(), edge.attributes.clone());
+        }
+    }
+
+    for (i, (first, e)) in links.iter().enumerate() {
+        for (second, f) in links.iter().skip(if graph.directed { 0 } else { i + 1 }) {
+            if first == second {
+                continue;
+            }
+
+            let adjacent = if graph.directed {
+                first.1 == second.0
+            } else {
+                first.0 == second.0
+                    || first.0 == second.1
+                    || first.1 == second.0
+                    || first.1 == second.1
+            };
+            if !adjacent {
+                continue;
+            }
+
+            let (weight, capacity) = (
+                policy.merge(e.weight, f.weight),
+                policy.merge(e.capacity, f.capacity),
+            );
+            if graph.directed {
+                result
+                    .add_edge(first.clone(), second.clone(), weight, capacity)
+                    .unwrap();
+            } else {
+                result
+                    .add_rib(first.clone(), second.clone(), weight, capacity)
+                    .unwrap();
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Степень графа G^k: вершины соединены, если одна достижима из другой не
+/// более чем за `k` шагов. Вес ребра получается объединением по `policy`
+/// весов вдоль кратчайшего по числу шагов пути (`Sum` даёт длину пути).
+pub fn power<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    k: usize,
+    policy: WeightPolicy,
+) -> Result<Graph<N, W>, ProductError> {
+    if graph.mixed {
+        return Err(ProductError::MixedGraph);
+    }
+
+    let mut edges: Lists<N, W> = graph
+        .nodes()
+        .map(|node| (node.clone(), BTreeSet::new()))
+        .collect();
+
+    for source in graph.nodes() {
+        // Для каждой достигнутой вершины: число шагов, вес и пропускная способность пути
+        let mut reached: BTreeMap<N, (usize, W, W)> = BTreeMap::new();
+        let mut queue = VecDeque::from([source.clone()]);
+
+        while let Some(node) = queue.pop_front() {
+            let current = reached.get(&node).copied();
+            if current.map_or(0, |(steps, _, _)| steps) >= k {
+                continue;
+            }
+
+            for edge in graph.out_edges(&node) {
+                if edge.node == *source || reached.contains_key(&edge.node) {
+                    continue;
+                }
+
+                let step = match current {
+                    Some((steps, weight, capacity)) => (
+                        steps + 1,
+                        policy.merge(weight, edge.weight),
+                        policy.merge(capacity, edge.capacity),
+                    ),
+                    None => (1, edge.weight, edge.capacity),
+                };
+                reached.insert(edge.node.clone(), step);
+                queue.push_back(edge.node.clone());
+            }
+        }
+
+        for (target, (_, weight, capacity)) in reached {
+            // Рёбра неориентированного графа строятся от меньшей вершины,
+            // чтобы обе половины получили один и тот же вес
+            if !graph.directed && target < *source {
+                continue;
+            }
+
+            if !graph.directed {
+                insert(&mut edges, target.clone(), source.clone(), weight, capacity);
+            }
+            insert(&mut edges, source.clone(), target, weight, capacity);
+        }
+    }
+
+    Ok(Graph::from_edges(graph.directed, edges))
+}
+
+fn insert<N: Node, W: Weight>(edges: &mut Lists<N, W>, from: N, to: N, weight: W, capacity: W) {
+    edges
+        .entry(from)
+        .or_default()
+        .insert(Edge::value(to).weight(weight).capacity(capacity).build());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(len: usize) -> Graph<usize> {
+        let mut graph = Graph::new(false);
+        for i in 0..len {
+            graph.add_node(i);
+        }
+        for i in 1..len {
+            graph.add_rib(i - 1, i, 1, 1).unwrap();
+        }
+        graph
+    }
+
+    fn edge_count<N: Node>(graph: &Graph<N>) -> usize {
+        graph.edges.values().map(|edges| edges.len()).sum::<usize>() / 2
+    }
+
+    #[test]
+    fn test_products_of_paths() {
+        let (p2, p3) = (path(2), path(3));
+
+        // Решётка 2x3
+        let grid = product(&p2, &p3, Product::Cartesian, WeightPolicy::Sum).unwrap();
+        assert_eq!(grid.edges.len(), 6);
+        assert_eq!(edge_count(&grid), 7);
+        assert!(grid.contains_edge(&(0, 1), &(1, 1)));
+        assert!(!grid.contains_edge(&(0, 0), &(1, 1)));
+
+        let tensor = product(&p2, &p3, Product::Tensor, WeightPolicy::Sum).unwrap();
+        assert_eq!(edge_count(&tensor), 4);
+        assert_eq!(tensor.edges[&(0, 0)].first().unwrap().weight, 2);
+
+        let strong = product(&p2, &p3, Product::Strong, WeightPolicy::Sum).unwrap();
+        assert_eq!(edge_count(&strong), 11);
+
+        let lex = product(&p2, &p3, Product::Lexicographic, WeightPolicy::Sum).unwrap();
+        assert_eq!(edge_count(&lex), 9 + 4);
+    }
+
+    #[test]
+    fn test_hypercube() {
+        let mut cube = path(2);
+        for _ in 1..3 {
+            cube = product(&cube, &path(2), Product::Cartesian, WeightPolicy::Min)
+                .unwrap()
+                .map_nodes(|(node, bit)| node * 2 + bit);
+        }
+
+        assert_eq!(cube.edges.len(), 8);
+        assert!(cube.edges.values().all(|edges| edges.len() == 3));
+    }
+
+    #[test]
+    fn test_line_graph_and_power() {
+        let p4 = path(4);
+
+        let line = line_graph(&p4, WeightPolicy::Sum).unwrap();
+        assert_eq!(line.edges.len(), 3);
+        assert!(line.contains_edge(&(0, 1, 0), &(1, 2, 0)));
+        assert!(!line.contains_edge(&(0, 1, 0), &(2, 3, 0)));
+
+        let square = power(&p4, 2, WeightPolicy::Sum).unwrap();
+        assert_eq!(edge_count(&square), 5);
+        let far = square.edges[&0].iter().find(|e| e.node == 2).unwrap();
+        assert_eq!(far.weight, 2);
+        assert!(!square.contains_edge(&0, &3));
+    }
+}
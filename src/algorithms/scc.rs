@@ -0,0 +1,110 @@
+// 5.13 DFS
+
+use crate::{
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+pub fn scc_count<N: Node, W: Weight>(graph: &Graph<N, W>) -> usize {
+    let frozen = graph.freeze();
+    let n = frozen.node_count();
+
+    // Первый проход: порядок выхода из вершин в исходном графе
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        let mut path = vec![(root, frozen.out_arcs(root))];
+        while let Some((node, arcs)) = path.last_mut() {
+            match arcs.next().map(|arc| frozen.target(arc)) {
+                Some(next) if !visited[next] => {
+                    visited[next] = true;
+                    path.push((next, frozen.out_arcs(next)));
+                }
+                Some(_) => {}
+                None => {
+                    order.push(*node);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    // Второй проход по входящим дугам, то есть по транспонированному графу
+    let mut visited = vec![false; n];
+    let mut component_count = 0;
+
+    while let Some(root) = order.pop() {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            for &arc in frozen.in_arcs(node) {
+                let prev = frozen.source(arc);
+                if !visited[prev] {
+                    visited[prev] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+
+        component_count += 1;
+    }
+
+    component_count
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use maplit::{btreemap, btreeset};
+
+    use crate::graph::Edge;
+
+    use super::*;
+
+    #[test]
+    fn test_scc_count_with_isolated_nodes() {
+        let mut graph: Graph<usize> = Graph::new(true);
+
+        graph.edges = btreemap! {
+            0 => btreeset! { Edge::value(1).build(), Edge::value(2).build(), Edge::value(3).build() },
+            1 => btreeset! { Edge::value(3).build(), Edge::value(4).build() },
+            2 => btreeset! { Edge::value(3).build() },
+            3 => btreeset! { Edge::value(1).build(), Edge::value(4).build() },
+            4 => BTreeSet::new(),
+            5 => BTreeSet::new(),
+        };
+
+        // {1, 3} и по одной компоненте на вершины 0, 2, 4 и 5
+        assert_eq!(scc_count(&graph), 5);
+    }
+
+    #[test]
+    fn test_scc_count() {
+        let mut graph: Graph<usize> = Graph::new(true);
+
+        graph.edges = btreemap! {
+            0 => btreeset! { Edge::value(1).build() },
+            1 => btreeset! { Edge::value(2).build() },
+            2 => btreeset! { Edge::value(0).build() },
+            3 => btreeset! { Edge::value(2).build(), Edge::value(4).build() },
+            4 => btreeset! { Edge::value(7).build(), Edge::value(3).build() },
+            5 => btreeset! { Edge::value(7).build() },
+            6 => btreeset! { Edge::value(5).build(), Edge::value(8).build() },
+            7 => btreeset! { Edge::value(6).build() },
+            8 => btreeset! { Edge::value(7).build() },
+        };
+
+        assert_eq!(scc_count(&graph), 3);
+    }
+}
@@ -0,0 +1,304 @@
+use std::collections::{BTreeMap, BTreeSet, btree_map::Entry};
+
+use thiserror::Error;
+
+use crate::{
+    convert::merge_edge,
+    graph::{Attributes, Edge, Graph, Node},
+    weight::{Weight, WeightPolicy},
+};
+
+#[derive(Clone, Debug, Error)]
+pub enum SetOpError {
+    #[error("One graph is directed and the other is undirected")]
+    DirectednessMismatch,
+
+    #[error("Mixed graphs are not supported")]
+    MixedGraph,
+}
+
+// Дуги графа по парам концов. Параллельные дуги сливаются по политике.
+type Arcs<N, W> = BTreeMap<(N, N), Edge<N, W>>;
+
+/// Объединение: все вершины и дуги обоих графов. Веса дуг, которые есть
+/// в обоих графах, объединяются по `policy`.
+pub fn union<N: Node, W: Weight>(
+    first: &Graph<N, W>,
+    second: &Graph<N, W>,
+    policy: WeightPolicy,
+) -> Result<Graph<N, W>, SetOpError> {
+    check(first, second)?;
+
+    let mut arcs = arcs(first, policy);
+    for (key, edge) in self::arcs(second, policy) {
+        match arcs.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(edge);
+            }
+            Entry::Occupied(mut entry) => merge_edge(entry.get_mut(), edge, policy),
+        }
+    }
+
+    let nodes = first
+        .edges
+        .keys()
+        .chain(second.edges.keys())
+        .cloned()
+        .collect();
+    Ok(build(first, second, nodes, arcs, policy))
+}
+
+/// Пересечение: общие вершины и дуги, которые есть в обоих графах
+pub fn intersection<N: Node, W: Weight>(
+    first: &Graph<N, W>,
+    second: &Graph<N, W>,
+    policy: WeightPolicy,
+) -> Result<Graph<N, W>, SetOpError> {
+    check(first, second)?;
+
+    let mut other = arcs(second, policy);
+    let arcs = arcs(first, policy)
+        .into_iter()
+        .filter_map(|(key, mut edge)| {
+            let right = other.remove(&key)?;
+            merge_edge(&mut edge, right, policy);
+            Some((key, edge))
+        })
+        .collect();
+
+    let nodes = first
+        .edges
+        .keys()
+        .filter(|node| second.contains_node(node))
+        .cloned()
+        .collect();
+    Ok(build(first, second, nodes, arcs, policy))
+}
+
+/// Разность: все вершины первого графа и его дуги, которых нет во втором
+pub fn difference<N: Node, W: Weight>(
+    first: &Graph<N, W>,
+    second: &Graph<N, W>,
+) -> Result<Graph<N, W>, SetOpError> {
+    check(first, second)?;
+
+    let other = arcs(second, WeightPolicy::Left);
+    let arcs = arcs(first, WeightPolicy::Left)
+        .into_iter()
+        .filter(|(key, _)| !other.contains_key(key))
+        .collect();
+
+    let nodes = first.edges.keys().cloned().collect();
+    Ok(build(first, second, nodes, arcs, WeightPolicy::Left))
+}
+
+/// Симметрическая разность: все вершины обоих графов и дуги, которые есть
+/// ровно в одном из них
+pub fn symmetric_difference<N: Node, W: Weight>(
+    first: &Graph<N, W>,
+    second: &Graph<N, W>,
+) -> Result<Graph<N, W>, SetOpError> {
+    check(first, second)?;
+
+    let mut arcs = arcs(first, WeightPolicy::Left);
+    for (key, edge) in self::arcs(second, WeightPolicy::Left) {
+        match arcs.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(edge);
+            }
+            Entry::Occupied(entry) => {
+                entry.remove();
+            }
+        }
+    }
+
+    let nodes = first
+        .edges
+        .keys()
+        .chain(second.edges.keys())
+        .cloned()
+        .collect();
+    Ok(build(first, second, nodes, arcs, WeightPolicy::Left))
+}
+
+/// Дополнение: те же вершины и все дуги (без петель), которых нет в графе.
+/// Новые дуги получают единичные вес и пропускную способность.
+pub fn complement<N: Node, W: Weight>(graph: &Graph<N, W>) -> Result<Graph<N, W>, SetOpError> {
+    check(graph, graph)?;
+
+    let mut result = Graph::new(graph.directed);
+    for node in graph.nodes() {
+        result.add_node(node.clone());
+    }
+    for from in graph.nodes() {
+        for to in graph.nodes() {
+            if from == to || graph.contains_edge(from, to) {
+                continue;
+            }
+
+            if graph.directed {
+                result
+                    .add_edge(from.clone(), to.clone(), W::one(), W::one())
+                    .unwrap();
+            } else if from < to {
+                result
+                    .add_rib(from.clone(), to.clone(), W::one(), W::one())
+                    .unwrap();
+            }
+        }
+    }
+    result.node_attributes = graph.node_attributes.clone();
+
+    Ok(result)
+}
+
+fn check<N: Node, W: Weight>(first: &Graph<N, W>, second: &Graph<N, W>) -> Result<(), SetOpError> {
+    if first.mixed || second.mixed {
+        return Err(SetOpError::MixedGraph);
+    }
+    if first.directed != second.directed {
+        return Err(SetOpError::DirectednessMismatch);
+    }
+
+    Ok(())
+}
+
+fn arcs<N: Node, W: Weight>(graph: &Graph<N, W>, policy: WeightPolicy) -> Arcs<N, W> {
+    let mut arcs = Arcs::new();
+    for (from, to, edge) in graph.arcs() {
+        let mut arc = edge.clone();
+        arc.id = 0;
+        match arcs.entry((from.clone(), to.clone())) {
+            Entry::Vacant(entry) => {
+                entry.insert(arc);
+            }
+            Entry::Occupied(mut entry) => merge_edge(entry.get_mut(), arc, policy),
+        }
+    }
+
+    arcs
+}
+
+fn build<N: Node, W: Weight>(
+    first: &Graph<N, W>,
+    second: &Graph<N, W>,
+    nodes: BTreeSet<N>,
+    arcs: Arcs<N, W>,
+    policy: WeightPolicy,
+) -> Graph<N, W> {
+    let mut edges: BTreeMap<N, BTreeSet<Edge<N, W>>> = nodes
+        .iter()
+        .map(|node| (node.clone(), BTreeSet::new()))
+        .collect();
+    for ((from, to), edge) in arcs {
+        // Дуга между вершинами, которых нет в результате, отбрасывается
+        if !nodes.contains(&from) || !nodes.contains(&to) {
+            continue;
+        }
+        edges.get_mut(&from).unwrap().insert(edge);
+    }
+
+    let mut graph = Graph::from_edges(first.directed, edges);
+    graph.node_attributes = nodes
+        .iter()
+        .filter_map(|node| {
+            let attributes = merge_attributes(
+                first.node_attributes.get(node),
+                second.node_attributes.get(node),
+                policy,
+            )?;
+            Some((node.clone(), attributes))
+        })
+        .collect();
+
+    graph
+}
+
+fn merge_attributes(
+    left: Option<&Attributes>,
+    right: Option<&Attributes>,
+    policy: WeightPolicy,
+) -> Option<Attributes> {
+    let (primary, secondary) = if policy == WeightPolicy::Right {
+        (right, left)
+    } else {
+        (left, right)
+    };
+
+    let mut attributes = secondary.cloned().unwrap_or_default();
+    attributes.extend(primary.cloned().unwrap_or_default());
+    (!attributes.is_empty()).then_some(attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(directed: bool, nodes: &[usize], edges: &[(usize, usize, i32)]) -> Graph<usize> {
+        let mut graph = Graph::new(directed);
+        for &node in nodes {
+            graph.add_node(node);
+        }
+        for &(from, to, weight) in edges {
+            if directed {
+                graph.add_edge(from, to, weight, 1).unwrap();
+            } else {
+                graph.add_rib(from, to, weight, 1).unwrap();
+            }
+        }
+        graph
+    }
+
+    fn weight(graph: &Graph<usize>, from: usize, to: usize) -> Option<i32> {
+        graph.edges[&from]
+            .iter()
+            .find(|e| e.node == to)
+            .map(|e| e.weight)
+    }
+
+    #[test]
+    fn test_union_and_intersection_merge_weights() {
+        let first = graph(false, &[1, 2, 3], &[(1, 2, 4), (2, 3, 1)]);
+        let second = graph(false, &[1, 2, 4], &[(1, 2, 6), (2, 4, 2)]);
+
+        let union = union(&first, &second, WeightPolicy::Max).unwrap();
+        assert_eq!(union.edges.len(), 4);
+        assert_eq!(weight(&union, 2, 1), Some(6));
+        assert_eq!(weight(&union, 4, 2), Some(2));
+
+        let common = intersection(&first, &second, WeightPolicy::Sum).unwrap();
+        assert_eq!(common.edges.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(weight(&common, 1, 2), Some(10));
+        assert_eq!(weight(&common, 2, 1), Some(10));
+    }
+
+    #[test]
+    fn test_differences_keep_all_nodes() {
+        let first = graph(true, &[1, 2, 3], &[(1, 2, 1), (2, 3, 1)]);
+        let second = graph(true, &[2, 3, 5], &[(2, 3, 1), (3, 5, 1)]);
+
+        let diff = difference(&first, &second).unwrap();
+        assert_eq!(diff.edges.len(), 3);
+        assert!(diff.contains_edge(&1, &2) && !diff.contains_edge(&2, &3));
+
+        let sym = symmetric_difference(&first, &second).unwrap();
+        assert_eq!(sym.edges.len(), 4);
+        assert!(sym.contains_edge(&1, &2) && sym.contains_edge(&3, &5));
+        assert!(!sym.contains_edge(&2, &3));
+    }
+
+    #[test]
+    fn test_complement() {
+        let path = graph(false, &[1, 2, 3], &[(1, 2, 1), (2, 3, 1)]);
+
+        let complement = complement(&path).unwrap();
+        assert!(complement.contains_edge(&1, &3) && complement.contains_edge(&3, &1));
+        assert!(!complement.contains_edge(&1, &2));
+        assert_eq!(complement.edges[&2].len(), 0);
+
+        assert!(matches!(
+            union(&path, &graph(true, &[], &[]), WeightPolicy::Min),
+            Err(SetOpError::DirectednessMismatch)
+        ));
+    }
+}
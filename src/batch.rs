@@ -0,0 +1,268 @@
+use std::fmt::{self, Debug, Display};
+
+use thiserror::Error;
+
+use crate::{
+    graph::{
+        Graph, GraphAddEdgeError, GraphAddRibError, GraphAttributeError, GraphRemoveEdgeError,
+        GraphRemoveNodeError, GraphRemoveRibError, Node,
+    },
+    weight::Weight,
+};
+
+/// Одна операция пакетного изменения графа
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operation<N, W> {
+    AddNode(N),
+    RemoveNode(N),
+    AddEdge {
+        from: N,
+        to: N,
+        weight: W,
+        capacity: W,
+    },
+    RemoveEdge {
+        from: N,
+        to: N,
+    },
+    AddRib {
+        first: N,
+        second: N,
+        weight: W,
+        capacity: W,
+    },
+    RemoveRib {
+        first: N,
+        second: N,
+    },
+    SetWeight {
+        from: N,
+        to: N,
+        weight: W,
+    },
+    SetCapacity {
+        from: N,
+        to: N,
+        capacity: W,
+    },
+}
+
+/// Ошибка отдельной операции пакета
+#[derive(Clone, Debug, Error)]
+pub enum OperationError<N: Debug> {
+    #[error(transparent)]
+    RemoveNode(#[from] GraphRemoveNodeError<N>),
+
+    #[error(transparent)]
+    AddEdge(#[from] GraphAddEdgeError<N>),
+
+    #[error(transparent)]
+    RemoveEdge(#[from] GraphRemoveEdgeError<N>),
+
+    #[error(transparent)]
+    AddRib(#[from] GraphAddRibError<N>),
+
+    #[error(transparent)]
+    RemoveRib(#[from] GraphRemoveRibError<N>),
+
+    #[error(transparent)]
+    Attribute(#[from] GraphAttributeError<N>),
+}
+
+/// Операция, которая не выполнилась, и её номер в пакете
+#[derive(Clone, Debug)]
+pub struct Failure<N: Debug, W> {
+    pub index: usize,
+    pub operation: Operation<N, W>,
+    pub error: OperationError<N>,
+}
+
+/// Все неудачные операции пакета. Граф при этом остаётся без изменений.
+#[derive(Clone, Debug)]
+pub struct BatchError<N: Debug, W>(pub Vec<Failure<N, W>>);
+
+impl<N: Debug, W: Debug> Display for BatchError<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} operation(s) failed, batch rolled back", self.0.len())?;
+        for failure in &self.0 {
+            write!(
+                f,
+                "\n  #{} {:?}: {}",
+                failure.index, failure.operation, failure.error
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Debug, W: Debug> std::error::Error for BatchError<N, W> {}
+
+impl<N: Node, W: Weight> Graph<N, W> {
+    /// Применяет операции атомарно. Операции выполняются по порядку на копии
+    /// графа; если хотя бы одна не удалась, граф не меняется, а ошибка
+    /// содержит все неудачные операции.
+    pub fn apply(
+        &mut self,
+        operations: impl IntoIterator<Item = Operation<N, W>>,
+    ) -> Result<(), BatchError<N, W>> {
+        let mut draft = self.clone();
+        let mut failures = Vec::new();
+
+        for (index, operation) in operations.into_iter().enumerate() {
+            if let Err(error) = draft.apply_one(operation.clone()) {
+                failures.push(Failure {
+                    index,
+                    operation,
+                    error,
+                });
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(BatchError(failures));
+        }
+
+        *self = draft;
+        Ok(())
+    }
+
+    /// Выполняет произвольные изменения атомарно: при ошибке граф
+    /// возвращается в исходное состояние.
+    pub fn transaction<T, E>(
+        &mut self,
+        f: impl FnOnce(&mut Graph<N, W>) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut draft = self.clone();
+        let result = f(&mut draft)?;
+        *self = draft;
+        Ok(result)
+    }
+
+    fn apply_one(&mut self, operation: Operation<N, W>) -> Result<(), OperationError<N>> {
+        match operation {
+            Operation::AddNode(node) => self.add_node(node),
+            Operation::RemoveNode(node) => self.remove_node(node)?,
+            Operation::AddEdge {
+                from,
+                to,
+                weight,
+                capacity,
+            } => {
+                self.add_edge(from, to, weight, capacity)?;
+            }
+            Operation::RemoveEdge { from, to } => self.remove_edge(from, to)?,
+            Operation::AddRib {
+                first,
+                second,
+                weight,
+                capacity,
+            } => {
+                self.add_rib(first, second, weight, capacity)?;
+            }
+            Operation::RemoveRib { first, second } => self.remove_rib(first, second)?,
+            Operation::SetWeight { from, to, weight } => self.set_weight(from, to, weight)?,
+            Operation::SetCapacity { from, to, capacity } => {
+                self.set_capacity(from, to, capacity)?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_rolls_back_and_reports_every_failure() {
+        let mut graph: Graph<char> = Graph::new(true);
+        graph.add_node('A');
+
+        let result = graph.apply([
+            Operation::AddNode('B'),
+            Operation::AddEdge {
+                from: 'A',
+                to: 'B',
+                weight: 2,
+                capacity: 1,
+            },
+            Operation::AddEdge {
+                from: 'A',
+                to: 'C',
+                weight: 1,
+                capacity: 1,
+            },
+            Operation::RemoveNode('D'),
+        ]);
+
+        let Err(BatchError(failures)) = result else {
+            panic!("batch must fail");
+        };
+        assert_eq!(
+            failures.iter().map(|f| f.index).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert!(matches!(
+            failures[0].error,
+            OperationError::AddEdge(GraphAddEdgeError::ToNodeDoesNotExist('C'))
+        ));
+        assert!(!graph.contains_node(&'B'));
+
+        graph
+            .apply([
+                Operation::AddNode('B'),
+                Operation::AddEdge {
+                    from: 'A',
+                    to: 'B',
+                    weight: 2,
+                    capacity: 1,
+                },
+                Operation::SetWeight {
+                    from: 'A',
+                    to: 'B',
+                    weight: 5,
+                },
+            ])
+            .unwrap();
+        assert_eq!(graph.edges[&'A'].first().unwrap().weight, 5);
+        assert_eq!(graph.in_degree(&'B'), 1);
+    }
+
+    #[test]
+    fn test_transaction_rolls_back_on_error() {
+        let mut graph: Graph<char> = Graph::new(true);
+        graph.add_node('A');
+        graph.add_node('B');
+        graph.add_edge('A', 'B', 2, 1).unwrap();
+        graph
+            .set_node_attribute('A', "color", "red".into())
+            .unwrap();
+
+        let result = graph.transaction(|draft| -> Result<(), OperationError<char>> {
+            draft.set_node_attribute('A', "color", "blue".into())?;
+            draft.add_node('C');
+            draft.add_edge('C', 'B', 1, 1)?;
+            draft.remove_node('A')?;
+            draft.remove_edge('A', 'C')?;
+            Ok(())
+        });
+
+        assert!(matches!(
+            result,
+            Err(OperationError::RemoveEdge(
+                GraphRemoveEdgeError::FromNodeDoesNotExist('A')
+            ))
+        ));
+        assert!(!graph.contains_node(&'C'));
+        assert_eq!(graph.node_attribute(&'A', "color"), Some(&"red".into()));
+        assert_eq!(graph.predecessors(&'B').collect::<Vec<_>>(), [&'A']);
+        assert_eq!(graph.in_degree(&'B'), 1);
+        assert_eq!(graph.edges[&'A'].first().unwrap().weight, 2);
+
+        graph
+            .transaction(|draft| draft.add_edge('B', 'A', 3, 1).map(|_| ()))
+            .unwrap();
+        assert_eq!(graph.predecessors(&'A').collect::<Vec<_>>(), [&'B']);
+    }
+}
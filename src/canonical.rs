@@ -0,0 +1,626 @@
+// Канонизация: уточнение раскраски вершин и перебор с индивидуализацией
+// вершин, как в nauty. Компоненты связности канонизируются отдельно, а их
+// коды сортируются. Перестановка вершин-близнецов не перебирается, а листья
+// с одинаковым кодом дают автоморфизмы: вершины одной орбиты не
+// индивидуализируются повторно, и перебор возвращается к общему префиксу.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use serde::Serialize;
+
+use crate::{
+    graph::{Edge, Graph, Node},
+    weight::Weight,
+};
+
+// Исходящие дуги вершины: номер конца и ранг метки дуги
+type Adjacency = Vec<Vec<(usize, usize)>>;
+
+// Дуга в канонической нумерации: начало, конец, ранг метки, исходное ребро
+// и ключ, общий для обеих половин ребра
+type Placed<'a, N, W> = (usize, usize, usize, &'a Edge<N, W>, (usize, &'a N, &'a N));
+
+impl<N: Node, W: Weight> Graph<N, W> {
+    /// Канонические номера вершин: у изоморфных графов (с учётом весов,
+    /// пропускных способностей и свойств) вершины, переходящие друг в друга
+    /// при изоморфизме, получают одинаковые номера
+    pub fn canonical_labelling(&self) -> BTreeMap<N, usize> {
+        let canon = Canon::new(self);
+        let positions = canon.search();
+
+        self.nodes().cloned().zip(positions).collect()
+    }
+
+    /// Граф с вершинами `0..n`, пронумерованными канонически. Изоморфные
+    /// графы дают один и тот же канонический граф, а значит, и одинаковый
+    /// JSON при сохранении. Идентификаторы рёбер мультиграфа назначаются
+    /// заново по порядку.
+    pub fn canonical_form(&self) -> Graph<usize, W> {
+        let labelling = self.canonical_labelling();
+        let labels = edge_labels(self);
+
+        let mut arcs: Vec<Placed<N, W>> = self
+            .arcs()
+            .map(|(from, to, edge)| {
+                // Половины ребра узнаются друг в друге по идентификатору и паре концов
+                let key = if from <= to {
+                    (edge.id, from, to)
+                } else {
+                    (edge.id, to, from)
+                };
+                (
+                    labelling[from],
+                    labelling[to],
+                    labels[&label(self, edge)],
+                    edge,
+                    key,
+                )
+            })
+            .collect();
+        arcs.sort_by_key(|&(from, to, label, _, _)| (from, to, label));
+
+        let mut ids = BTreeMap::new();
+        let mut next_id = 1;
+        let mut edges: BTreeMap<usize, BTreeSet<Edge<usize, W>>> = (0..labelling.len())
+            .map(|node| (node, BTreeSet::new()))
+            .collect();
+        for (from, to, _, edge, key) in arcs {
+            let id = if !self.multigraph {
+                0
+            } else if self.is_rib(edge) {
+                *ids.entry(key).or_insert_with(|| {
+                    next_id += 1;
+                    next_id - 1
+                })
+            } else {
+                next_id += 1;
+                next_id - 1
+            };
+
+            let mut canonical = Edge::value(to)
+                .weight(edge.weight)
+                .capacity(edge.capacity)
+                .id(id)
+                .rib(edge.rib)
+                .build();
+            canonical.attributes = edge.attributes.clone();
+            edges.get_mut(&from).unwrap().insert(canonical);
+        }
+
+        let mut graph = Graph::from_edges(self.directed, edges);
+        graph.multigraph = self.multigraph;
+        graph.mixed = self.mixed;
+        graph.node_attributes = self
+            .node_attributes
+            .iter()
+            .map(|(node, attributes)| (labelling[node], attributes.clone()))
+            .collect();
+        graph
+    }
+
+    /// Отпечаток графа: 64-битный FNV-1a от JSON канонической формы.
+    /// Одинаков у изоморфных графов и не меняется между запусками.
+    pub fn fingerprint(&self) -> u64
+    where
+        W: Serialize,
+    {
+        let json = serde_json::to_string(&self.canonical_form())
+            .expect("canonical graph is always serializable");
+
+        json.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+// Всё, что попадает в JSON ребра, кроме конца и идентификатора
+fn label<N: Node, W: Weight>(graph: &Graph<N, W>, edge: &Edge<N, W>) -> String {
+    format!(
+        "{}|{:?}|{:?}|{}",
+        graph.is_rib(edge),
+        edge.weight,
+        edge.capacity,
+        serde_json::Value::from(serde_json::Map::from_iter(edge.attributes.clone()))
+    )
+}
+
+// Ранги меток рёбер: зависят только от содержимого, а не от имён вершин
+fn edge_labels<N: Node, W: Weight>(graph: &Graph<N, W>) -> BTreeMap<String, usize> {
+    let labels: BTreeSet<String> = graph
+        .arcs()
+        .map(|(_, _, edge)| label(graph, edge))
+        .collect();
+    labels
+        .into_iter()
+        .enumerate()
+        .map(|(rank, label)| (label, rank))
+        .collect()
+}
+
+struct Canon {
+    successors: Adjacency,
+    predecessors: Adjacency,
+    initial: Vec<usize>,
+}
+
+// Лист дерева перебора: код графа и позиции вершин в этой нумерации
+type Leaf = (Adjacency, Vec<usize>);
+
+// Состояние перебора в одной компоненте связности
+#[derive(Default)]
+struct Search {
+    // Первый найденный лист и путь к нему
+    first: Option<Leaf>,
+    first_path: Vec<usize>,
+    best: Option<Leaf>,
+    // Автоморфизмы, найденные по листьям с одинаковым кодом
+    automorphisms: Vec<Vec<usize>>,
+}
+
+impl Canon {
+    fn new<N: Node, W: Weight>(graph: &Graph<N, W>) -> Self {
+        let index: BTreeMap<&N, usize> = graph.nodes().enumerate().map(|(i, n)| (n, i)).collect();
+        let labels = edge_labels(graph);
+
+        let n = index.len();
+        let mut successors = vec![Vec::new(); n];
+        let mut predecessors = vec![Vec::new(); n];
+        for (from, to, edge) in graph.arcs() {
+            let label = labels[&label(graph, edge)];
+            successors[index[from]].push((index[to], label));
+            predecessors[index[to]].push((index[from], label));
+        }
+        for list in successors.iter_mut().chain(predecessors.iter_mut()) {
+            list.sort();
+        }
+
+        // Начальный цвет — свойства вершины; структура учитывается уточнением
+        let attributes: Vec<String> = graph
+            .nodes()
+            .map(|node| {
+                let attributes = graph.node_attributes.get(node).cloned().unwrap_or_default();
+                serde_json::Value::from(serde_json::Map::from_iter(attributes)).to_string()
+            })
+            .collect();
+
+        Canon {
+            successors,
+            predecessors,
+            initial: ranks(&attributes),
+        }
+    }
+
+    // Позиция каждой вершины в канонической нумерации. Компоненты связности
+    // канонизируются по отдельности и идут по возрастанию своих кодов, так
+    // что перестановки одинаковых компонент не перебираются.
+    fn search(&self) -> Vec<usize> {
+        let mut parts: Vec<_> = self
+            .components()
+            .into_iter()
+            .map(|vertices| {
+                let part = self.restrict(&vertices);
+                let (code, positions) = part.search_connected();
+                let mut initial = vec![0; vertices.len()];
+                for (u, &position) in positions.iter().enumerate() {
+                    initial[position] = part.initial[u];
+                }
+                ((initial, code), vertices, positions)
+            })
+            .collect();
+        parts.sort_by(|(first, _, _), (second, _, _)| first.cmp(second));
+
+        let mut positions = vec![0; self.initial.len()];
+        let mut offset = 0;
+        for (_, vertices, local) in parts {
+            for (&u, position) in vertices.iter().zip(local) {
+                positions[u] = offset + position;
+            }
+            offset += vertices.len();
+        }
+        positions
+    }
+
+    // Вершины компонент слабой связности, каждая по возрастанию
+    fn components(&self) -> Vec<Vec<usize>> {
+        let mut component = vec![usize::MAX; self.initial.len()];
+        let mut components = Vec::new();
+        for start in 0..self.initial.len() {
+            if component[start] != usize::MAX {
+                continue;
+            }
+            component[start] = components.len();
+            let mut vertices = vec![start];
+            let mut next = 0;
+            while let Some(&u) = vertices.get(next) {
+                next += 1;
+                for &(w, _) in self.successors[u].iter().chain(&self.predecessors[u]) {
+                    if component[w] == usize::MAX {
+                        component[w] = components.len();
+                        vertices.push(w);
+                    }
+                }
+            }
+            vertices.sort();
+            components.push(vertices);
+        }
+        components
+    }
+
+    // Подграф на вершинах одной компоненты с локальной нумерацией. Начальные
+    // цвета остаются общими, чтобы коды компонент можно было сравнивать.
+    fn restrict(&self, vertices: &[usize]) -> Canon {
+        let local: BTreeMap<usize, usize> =
+            vertices.iter().enumerate().map(|(i, &u)| (u, i)).collect();
+        let adjacency = |lists: &Adjacency| -> Adjacency {
+            vertices
+                .iter()
+                .map(|&u| {
+                    let mut list: Vec<_> = lists[u]
+                        .iter()
+                        .map(|&(w, label)| (local[&w], label))
+                        .collect();
+                    list.sort();
+                    list
+                })
+                .collect()
+        };
+
+        Canon {
+            successors: adjacency(&self.successors),
+            predecessors: adjacency(&self.predecessors),
+            initial: vertices.iter().map(|&u| self.initial[u]).collect(),
+        }
+    }
+
+    // Наименьший код среди листьев дерева перебора и позиции вершин в нём
+    fn search_connected(&self) -> Leaf {
+        let mut search = Search::default();
+        self.explore(
+            self.refine(self.initial.clone()),
+            &mut Vec::new(),
+            &mut search,
+        );
+        search.best.unwrap_or_default()
+    }
+
+    // Обходит поддерево перебора. Возвращает глубину, на которую нужно
+    // вернуться, если поддерево оказалось образом уже пройденного.
+    fn explore(
+        &self,
+        colors: Vec<usize>,
+        path: &mut Vec<usize>,
+        search: &mut Search,
+    ) -> Option<usize> {
+        let Some(cell) = self.first_cell(&colors) else {
+            return self.leaf(colors, path, search);
+        };
+
+        let mut tried: Vec<usize> = Vec::new();
+        for &v in &cell {
+            if tried.iter().any(|&u| self.twins(u, v)) || same_orbit(&tried, v, path, search) {
+                continue;
+            }
+            tried.push(v);
+
+            // Выбранная вершина получает цвет меньше остальных вершин ячейки
+            let individualized = colors
+                .iter()
+                .enumerate()
+                .map(|(u, &color)| (color, u != v && color == colors[v]))
+                .collect::<Vec<_>>();
+            path.push(v);
+            let jump = self.explore(self.refine(ranks(&individualized)), path, search);
+            path.pop();
+
+            if let Some(depth) = jump.filter(|&depth| depth < path.len()) {
+                return Some(depth);
+            }
+        }
+        None
+    }
+
+    // Лист с тем же кодом, что у первого, задаёт автоморфизм, который
+    // переводит всё поддерево от общего предка в уже пройденное, поэтому
+    // перебор возвращается к этому предку
+    fn leaf(&self, colors: Vec<usize>, path: &[usize], search: &mut Search) -> Option<usize> {
+        let code = self.code(&colors);
+        let Some((first_code, first)) = &search.first else {
+            search.first = Some((code.clone(), colors.clone()));
+            search.first_path = path.to_vec();
+            search.best = Some((code, colors));
+            return None;
+        };
+
+        if code == *first_code {
+            search.automorphisms.push(automorphism(&colors, first));
+            let common = path
+                .iter()
+                .zip(&search.first_path)
+                .take_while(|(u, v)| u == v)
+                .count();
+            return Some(common);
+        }
+
+        let Some((best_code, best)) = &search.best else {
+            return None;
+        };
+        match code.cmp(best_code) {
+            Ordering::Less => search.best = Some((code, colors)),
+            Ordering::Equal => {
+                let automorphism = automorphism(&colors, best);
+                search.automorphisms.push(automorphism);
+            }
+            Ordering::Greater => {}
+        }
+        None
+    }
+
+    // Уточняет раскраску, пока вершины одного цвета различаются цветами соседей
+    fn refine(&self, mut colors: Vec<usize>) -> Vec<usize> {
+        loop {
+            let signatures: Vec<_> = (0..colors.len())
+                .map(|u| {
+                    let neighbours = |list: &[(usize, usize)]| {
+                        let mut around: Vec<_> =
+                            list.iter().map(|&(w, label)| (colors[w], label)).collect();
+                        around.sort();
+                        around
+                    };
+                    (
+                        colors[u],
+                        neighbours(&self.successors[u]),
+                        neighbours(&self.predecessors[u]),
+                    )
+                })
+                .collect();
+
+            let refined = ranks(&signatures);
+            if count(&refined) == count(&colors) {
+                return refined;
+            }
+            colors = refined;
+        }
+    }
+
+    fn first_cell(&self, colors: &[usize]) -> Option<Vec<usize>> {
+        let mut cells: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (u, &color) in colors.iter().enumerate() {
+            cells.entry(color).or_default().push(u);
+        }
+        cells.into_values().find(|cell| cell.len() > 1)
+    }
+
+    // Списки смежности в канонической нумерации
+    fn code(&self, positions: &[usize]) -> Adjacency {
+        let mut code = vec![Vec::new(); positions.len()];
+        for (u, list) in self.successors.iter().enumerate() {
+            let mut arcs: Vec<_> = list
+                .iter()
+                .map(|&(w, label)| (positions[w], label))
+                .collect();
+            arcs.sort();
+            code[positions[u]] = arcs;
+        }
+        code
+    }
+
+    // Вершины-близнецы одинаково связаны со всеми остальными вершинами,
+    // поэтому их перестановка — автоморфизм и перебирать обе не нужно
+    fn twins(&self, u: usize, v: usize) -> bool {
+        let swap = |w: usize| match w {
+            w if w == u => v,
+            w if w == v => u,
+            w => w,
+        };
+        let same = |list: &Adjacency| {
+            let mut swapped: Vec<_> = list[u].iter().map(|&(w, label)| (swap(w), label)).collect();
+            swapped.sort();
+            swapped == list[v]
+        };
+        same(&self.successors) && same(&self.predecessors)
+    }
+}
+
+// Перестановка вершин, переводящая лист `positions` в лист `target` с тем же
+// кодом: вершина переходит в вершину с той же позицией
+fn automorphism(positions: &[usize], target: &[usize]) -> Vec<usize> {
+    let mut at = vec![0; target.len()];
+    for (w, &position) in target.iter().enumerate() {
+        at[position] = w;
+    }
+    positions.iter().map(|&position| at[position]).collect()
+}
+
+// Лежит ли `v` в одной орбите с уже перебранной вершиной относительно
+// найденных автоморфизмов, оставляющих на месте все вершины пути. Такие
+// автоморфизмы переводят поддерево перебранной вершины в поддерево `v`.
+fn same_orbit(tried: &[usize], v: usize, path: &[usize], search: &Search) -> bool {
+    let fixing: Vec<_> = search
+        .automorphisms
+        .iter()
+        .filter(|gamma| path.iter().all(|&u| gamma[u] == u))
+        .collect();
+    if tried.is_empty() || fixing.is_empty() {
+        return false;
+    }
+
+    // Орбиты — компоненты объединения циклов автоморфизмов
+    let mut orbit: Vec<usize> = (0..fixing[0].len()).collect();
+    for gamma in fixing {
+        for (u, &w) in gamma.iter().enumerate() {
+            let (root_u, root_w) = (root(&mut orbit, u), root(&mut orbit, w));
+            orbit[root_u.max(root_w)] = root_u.min(root_w);
+        }
+    }
+    let target = root(&mut orbit, v);
+    tried.iter().any(|&u| root(&mut orbit, u) == target)
+}
+
+fn root(parent: &mut [usize], mut u: usize) -> usize {
+    while parent[u] != u {
+        parent[u] = parent[parent[u]];
+        u = parent[u];
+    }
+    u
+}
+
+// Номера различных значений по возрастанию
+fn ranks<T: Ord + Clone>(values: &[T]) -> Vec<usize> {
+    let distinct: BTreeSet<&T> = values.iter().collect();
+    let rank: BTreeMap<&T, usize> = distinct
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (v, i))
+        .collect();
+    values.iter().map(|value| rank[value]).collect()
+}
+
+fn count(colors: &[usize]) -> usize {
+    colors.iter().collect::<BTreeSet<_>>().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::isomorphism::{Compare, is_isomorphic};
+
+    use super::*;
+
+    fn path(directed: bool, nodes: &[char], weights: &[i32]) -> Graph<char> {
+        let mut graph = Graph::new(directed);
+        for &node in nodes {
+            graph.add_node(node);
+        }
+        for (pair, &weight) in nodes.windows(2).zip(weights) {
+            if directed {
+                graph.add_edge(pair[0], pair[1], weight, 1).unwrap();
+            } else {
+                graph.add_rib(pair[0], pair[1], weight, 1).unwrap();
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_isomorphic_graphs_share_canonical_form() {
+        let first = path(true, &['A', 'B', 'C', 'D'], &[1, 2, 3]);
+        let second = path(true, &['Z', 'X', 'Y', 'W'], &[1, 2, 3]);
+
+        assert_eq!(
+            serde_json::to_string(&first.canonical_form()).unwrap(),
+            serde_json::to_string(&second.canonical_form()).unwrap()
+        );
+        assert_eq!(first.fingerprint(), second.fingerprint());
+        assert!(is_isomorphic(
+            &first,
+            &first.canonical_form(),
+            Compare {
+                weights: true,
+                attributes: true
+            }
+        ));
+
+        let heavier = path(true, &['A', 'B', 'C', 'D'], &[1, 2, 4]);
+        assert_ne!(first.fingerprint(), heavier.fingerprint());
+        assert_ne!(first.fingerprint(), first.reversed().fingerprint());
+    }
+
+    #[test]
+    fn test_symmetric_graphs() {
+        let mut empty: Graph<usize> = Graph::new(false);
+        let mut complete: Graph<usize> = Graph::new(false);
+        for node in 0..12 {
+            empty.add_node(node);
+            complete.add_node(node);
+        }
+        for first in 0..12 {
+            for second in first + 1..12 {
+                complete.add_rib(first, second, 1, 1).unwrap();
+            }
+        }
+        assert_eq!(empty.canonical_labelling().len(), 12);
+        assert_eq!(complete.canonical_form().edges[&0].len(), 11);
+
+        // Цикл с разной нумерацией вершин
+        let mut cycle = path(false, &['A', 'B', 'C', 'D', 'E', 'F'], &[1; 5]);
+        cycle.add_rib('F', 'A', 1, 1).unwrap();
+        let mut shuffled = path(false, &['C', 'F', 'A', 'E', 'B', 'D'], &[1; 5]);
+        shuffled.add_rib('D', 'C', 1, 1).unwrap();
+        assert_eq!(cycle.fingerprint(), shuffled.fingerprint());
+    }
+
+    fn ribs(nodes: usize, pairs: impl IntoIterator<Item = (usize, usize)>) -> Graph<usize> {
+        let mut graph = Graph::new(false);
+        for node in 0..nodes {
+            graph.add_node(node);
+        }
+        for (first, second) in pairs {
+            graph.add_rib(first, second, 1, 1).unwrap();
+        }
+        graph
+    }
+
+    // Циклы заданных длин на вершинах, взятых по порядку из `order`
+    fn cycles(order: &[usize], lengths: &[usize]) -> Graph<usize> {
+        let mut pairs = Vec::new();
+        let mut rest = order;
+        for &length in lengths {
+            let (cycle, tail) = rest.split_at(length);
+            pairs.extend((0..length).map(|i| (cycle[i], cycle[(i + 1) % length])));
+            rest = tail;
+        }
+        ribs(order.len(), pairs)
+    }
+
+    #[test]
+    fn test_disconnected_symmetric_graphs() {
+        // Паросочетание из 24 рёбер: перебор перестановок рёбер был бы
+        // факториальным
+        let matching = ribs(48, (0..24).map(|i| (2 * i, 2 * i + 1)));
+        let shuffled = ribs(48, (0..24).map(|i| (i, 47 - i)));
+        assert_eq!(matching.fingerprint(), shuffled.fingerprint());
+        assert_eq!(
+            serde_json::to_string(&matching.canonical_form()).unwrap(),
+            serde_json::to_string(&shuffled.canonical_form()).unwrap()
+        );
+
+        // Четыре цикла длины 5 и три цикла длины 4 в разном порядке
+        let order: Vec<usize> = (0..32).collect();
+        let reversed: Vec<usize> = (0..32).rev().collect();
+        let union = cycles(&order, &[5, 5, 5, 5, 4, 4, 4]);
+        let mixed = cycles(&reversed, &[4, 5, 4, 5, 5, 4, 5]);
+        assert_eq!(union.fingerprint(), mixed.fingerprint());
+
+        // Все вершины имеют степень 2, но компоненты другие
+        let other = cycles(&order, &[5, 5, 5, 5, 6, 6]);
+        assert_ne!(union.fingerprint(), other.fingerprint());
+    }
+
+    #[test]
+    fn test_automorphism_pruning_in_connected_graph() {
+        // Центр и 12 путей длины 3: вершины путей не близнецы, поэтому
+        // одинаковые ветви отсекаются только по найденным автоморфизмам
+        let spider = |order: &[usize]| {
+            ribs(
+                37,
+                order
+                    .chunks(3)
+                    .flat_map(|leg| [(0, leg[0]), (leg[0], leg[1]), (leg[1], leg[2])]),
+            )
+        };
+        let order: Vec<usize> = (1..=36).collect();
+        let mut shuffled: Vec<usize> = (1..=36).rev().collect();
+        shuffled.rotate_left(7);
+        assert_eq!(
+            spider(&order).fingerprint(),
+            spider(&shuffled).fingerprint()
+        );
+
+        let mut bent = spider(&order);
+        bent.remove_rib(35, 36).unwrap();
+        bent.add_rib(32, 36, 1, 1).unwrap();
+        assert_ne!(spider(&order).fingerprint(), bent.fingerprint());
+    }
+}
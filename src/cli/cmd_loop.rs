@@ -1,21 +1,21 @@
 use std::io::Write;
 
-use crate::{cli::dispatch_cmd::dispatch_cmd, graph::Graph};
+use sgu_graphs::{graph::Graph, label::Label};
+
+use crate::cli::session::Session;
+
+pub fn cmd_loop(graph: Graph<Label, f64>, start: Option<Vec<String>>) {
+    let mut session = Session::new(graph, start);
 
-pub fn cmd_loop(mut graph: Graph) {
     loop {
         print!("> ");
         std::io::stdout().flush().unwrap();
 
         let mut input = String::new();
         std::io::stdin().read_line(&mut input).unwrap();
-        let input: Vec<String> = input
-            .trim()
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect();
+        let input: Vec<String> = input.split_whitespace().map(|s| s.to_string()).collect();
 
-        match dispatch_cmd(&input, &mut graph) {
+        match session.execute(&input) {
             Ok(true) => continue,
             Ok(false) => break,
             Err(e) => eprintln!("{e}"),
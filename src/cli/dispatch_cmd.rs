@@ -1,55 +1,127 @@
-use crate::{
-    cli::{
-        handlers::{
-            add_arc::add_arc_cmd, add_node::add_node_cmd, add_rib::add_rib_cmd,
-            ford_fulkerson::ford_fulkerson_cmd, in_degree::in_degree_cmd,
-            is_acyclic::is_acyclic_cmd, min_dist::min_dist_cmd, min_len::min_len_cmd,
-            mst_prim::mst_prim_cmd, n_per::n_per_cmd,
-            node_with_greater_outdegree::node_with_greater_outdegree_cmd,
-            out_degree::out_degree_cmd, remove_arc::remove_arc_cmd, remove_node::remove_node_cmd,
-            remove_rib::remove_rib_cmd, save::save_cmd, sym_diff::sym_diff_cmd,
-        },
-        print_graph::print_graph,
+use sgu_graphs::{algorithms::scc::scc_count, graph::Graph, label::Label};
+
+use crate::cli::{
+    handlers::{
+        add_arc::add_arc_cmd, add_node::add_node_cmd, add_rib::add_rib_cmd,
+        complement::complement_cmd, difference::difference_cmd, edge_attrs::edge_attrs_cmd,
+        export_dot::export_dot_cmd, ford_fulkerson::ford_fulkerson_cmd, in_degree::in_degree_cmd,
+        intersection::intersection_cmd, is_acyclic::is_acyclic_cmd, isomorphic::isomorphic_cmd,
+        line_graph::line_graph_cmd, load::load_cmd, matrix::matrix_cmd, min_dist::min_dist_cmd,
+        min_len::min_len_cmd, mst_prim::mst_prim_cmd, n_per::n_per_cmd, node_attrs::node_attrs_cmd,
+        node_with_greater_outdegree::node_with_greater_outdegree_cmd, out_degree::out_degree_cmd,
+        power::power_cmd, product::product_cmd, remove_arc::remove_arc_cmd,
+        remove_node::remove_node_cmd, remove_rib::remove_rib_cmd, reverse::reverse_cmd,
+        save::save_cmd, set_capacity::set_capacity_cmd, set_edge_attr::set_edge_attr_cmd,
+        set_node_attr::set_node_attr_cmd, set_weight::set_weight_cmd, sym_diff::sym_diff_cmd,
+        to_directed::to_directed_cmd, to_undirected::to_undirected_cmd, union::union_cmd,
+        validate::validate_cmd,
     },
-    graph::Graph,
-    tasks::task5::scc_count,
+    print_graph::print_graph,
+    weight_source::weight_source,
 };
 
-pub fn dispatch_cmd(cmd_parts: &[String], mut graph: &mut Graph) -> Result<bool, String> {
-    let Some(cmd) = cmd_parts.get(0) else {
+/// Может ли команда изменить граф. Перед такими командами сессия сохраняет
+/// снимок графа для отмены, остальные выполняются без копирования.
+pub fn mutates_graph(cmd: &str) -> bool {
+    matches!(
+        cmd,
+        "clean"
+            | "add_node"
+            | "remove_node"
+            | "add_arc"
+            | "add_rib"
+            | "remove_arc"
+            | "remove_rib"
+            | "sym_diff"
+            | "union"
+            | "intersection"
+            | "difference"
+            | "complement"
+            | "product"
+            | "line_graph"
+            | "power"
+            | "set_weight"
+            | "set_capacity"
+            | "set_node_attr"
+            | "set_edge_attr"
+            | "mst_prim"
+            | "reverse"
+            | "to_undirected"
+            | "to_directed"
+            | "validate"
+            | "load"
+    )
+}
+
+pub fn dispatch_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    // Алгоритмы могут брать веса из свойств рёбер: `min_len 1 --weight time`
+    let (cmd_parts, weighted) = weight_source(cmd_parts, graph)?;
+    let cmd_parts = cmd_parts.as_slice();
+
+    let Some(cmd) = cmd_parts.first() else {
         return Err("Вы должны указать команду".to_string());
     };
 
     match cmd.as_str() {
-        "print" => Ok(print_graph(&graph)),
+        "print" => Ok(print_graph(graph)),
         "clean" => {
-            *graph = Graph::new(graph.directed);
-            Ok(print_graph(&graph))
+            graph.clear();
+            Ok(print_graph(graph))
         }
-        "add_node" => add_node_cmd(cmd_parts, &mut graph),
-        "remove_node" => remove_node_cmd(cmd_parts, &mut graph),
-        "add_arc" => add_arc_cmd(cmd_parts, &mut graph),
-        "add_rib" => add_rib_cmd(cmd_parts, &mut graph),
-        "remove_arc" => remove_arc_cmd(cmd_parts, &mut graph),
-        "remove_rib" => remove_rib_cmd(cmd_parts, &mut graph),
-        "out_degree" => out_degree_cmd(cmd_parts, &mut graph),
-        "in_degree" => in_degree_cmd(cmd_parts, &mut graph),
-        "node_with_greater_outdegree" => node_with_greater_outdegree_cmd(cmd_parts, &mut graph),
-        "sym_diff" => sym_diff_cmd(cmd_parts, &mut graph),
+        "add_node" => add_node_cmd(cmd_parts, graph),
+        "remove_node" => remove_node_cmd(cmd_parts, graph),
+        "add_arc" => add_arc_cmd(cmd_parts, graph),
+        "add_rib" => add_rib_cmd(cmd_parts, graph),
+        "remove_arc" => remove_arc_cmd(cmd_parts, graph),
+        "remove_rib" => remove_rib_cmd(cmd_parts, graph),
+        "out_degree" => out_degree_cmd(cmd_parts, graph),
+        "in_degree" => in_degree_cmd(cmd_parts, graph),
+        "node_with_greater_outdegree" => node_with_greater_outdegree_cmd(cmd_parts, graph),
+        "sym_diff" => sym_diff_cmd(cmd_parts, graph),
+        "union" => union_cmd(cmd_parts, graph),
+        "intersection" => intersection_cmd(cmd_parts, graph),
+        "difference" => difference_cmd(cmd_parts, graph),
+        "complement" => complement_cmd(cmd_parts, graph),
+        "product" => product_cmd(cmd_parts, graph),
+        "line_graph" => line_graph_cmd(cmd_parts, graph),
+        "power" => power_cmd(cmd_parts, graph),
+        "set_weight" => set_weight_cmd(cmd_parts, graph),
+        "set_capacity" => set_capacity_cmd(cmd_parts, graph),
+        "set_node_attr" => set_node_attr_cmd(cmd_parts, graph),
+        "node_attrs" => node_attrs_cmd(cmd_parts, graph),
+        "set_edge_attr" => set_edge_attr_cmd(cmd_parts, graph),
+        "edge_attrs" => edge_attrs_cmd(cmd_parts, graph),
         "scc_count" => {
-            println!("Количество сильно связных компонент: {}", scc_count(&graph));
+            println!("Количество сильно связных компонент: {}", scc_count(graph));
             Ok(true)
         }
-        "is_acyclic" => is_acyclic_cmd(&graph),
-        "mst_prim" => mst_prim_cmd(cmd_parts, &mut graph),
-        "min_len" => min_len_cmd(cmd_parts, &graph),
-        "min_dist" => min_dist_cmd(&graph),
-        "n_per" => n_per_cmd(cmd_parts, &graph),
-        "ford_fulkerson" => ford_fulkerson_cmd(cmd_parts, &graph),
-        "save" => save_cmd(cmd_parts, &mut graph),
+        "is_acyclic" => is_acyclic_cmd(graph),
+        "isomorphic" => isomorphic_cmd(cmd_parts, graph),
+        "mst_prim" => match weighted {
+            Some(mut weighted) => {
+                let result = mst_prim_cmd(cmd_parts, &mut weighted);
+                if result.is_ok() {
+                    *graph = weighted;
+                }
+                result
+            }
+            None => mst_prim_cmd(cmd_parts, graph),
+        },
+        "min_len" => min_len_cmd(cmd_parts, weighted.as_ref().unwrap_or(graph)),
+        "min_dist" => min_dist_cmd(weighted.as_ref().unwrap_or(graph)),
+        "n_per" => n_per_cmd(cmd_parts, weighted.as_ref().unwrap_or(graph)),
+        "ford_fulkerson" => ford_fulkerson_cmd(cmd_parts, weighted.as_ref().unwrap_or(graph)),
+        "reverse" => reverse_cmd(cmd_parts, graph),
+        "to_undirected" => to_undirected_cmd(cmd_parts, graph),
+        "to_directed" => to_directed_cmd(cmd_parts, graph),
+        "validate" => validate_cmd(cmd_parts, graph),
+        "load" => load_cmd(cmd_parts, graph),
+        "save" => save_cmd(cmd_parts, graph),
+        "export_dot" => export_dot_cmd(cmd_parts, weighted.as_ref().unwrap_or(graph)),
+        "matrix" => matrix_cmd(cmd_parts, weighted.as_ref().unwrap_or(graph)),
         "exit" => {
             println!("Good luck with that!");
-            return Ok(false);
+            Ok(false)
         }
         "" => Err("Вы должны указать команду".to_string()),
         _ => Err("Неизвестная команда".to_string()),
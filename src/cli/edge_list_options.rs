@@ -0,0 +1,42 @@
+use sgu_graphs::files::edge_list::EdgeList;
+
+/// Разбирает настройки списка рёбер после слова `csv`: `directed` или
+/// `undirected`, `header` или `no_header`, `delimiter <символ>` (а также
+/// `tab` и `space`). Если `directed` не задано, ориентированность обязательна.
+pub fn edge_list_options(options: &[String], directed: Option<bool>) -> Result<EdgeList, String> {
+    let mut directed = directed;
+    let mut delimiter = None;
+    let mut header = None;
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "directed" => directed = Some(true),
+            "undirected" => directed = Some(false),
+            "header" => header = Some(true),
+            "no_header" => header = Some(false),
+            "delimiter" => {
+                let raw = options
+                    .next()
+                    .ok_or("Вы должны указать разделитель после delimiter")?;
+                let mut chars = raw.chars();
+                delimiter = Some(match (raw.as_str(), chars.next(), chars.next()) {
+                    ("tab", _, _) => '\t',
+                    ("space", _, _) => ' ',
+                    (_, Some(c), None) => c,
+                    _ => return Err(format!("Разделитель {raw} должен быть одним символом")),
+                });
+            }
+            other => return Err(format!("Неизвестная настройка списка рёбер: {other}")),
+        }
+    }
+
+    let Some(directed) = directed else {
+        return Err("Укажите, ориентирован ли граф: directed или undirected".to_string());
+    };
+    Ok(EdgeList::builder()
+        .directed(directed)
+        .maybe_delimiter(delimiter)
+        .maybe_header(header)
+        .build())
+}
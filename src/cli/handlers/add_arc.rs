@@ -1,45 +1,34 @@
-use crate::{
-    cli::print_graph::print_graph,
-    graph::{Graph, GraphAddEdgeError},
-};
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
 
-pub fn add_arc_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn add_arc_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
     let Some(from) = cmd_parts.get(1) else {
         return Err("Вы должны указать начальную вершину".to_string());
     };
-    let Ok(from) = from.trim().parse() else {
-        return Err("Начальная вершина должна быть числом".to_string());
-    };
+    let from = Label::from(from.trim());
     let Some(to) = cmd_parts.get(2) else {
         return Err("Вы должны указать конечную вершину".to_string());
     };
-    let Ok(to) = to.trim().parse() else {
-        return Err("Конечная вершина должна быть числом".to_string());
-    };
+    let to = Label::from(to.trim());
 
     let weight = match cmd_parts.get(3).map(|w| w.parse()) {
         Some(Ok(weight)) => weight,
         Some(Err(_)) => return Err("Вес должен быть числом".to_string()),
-        None => 1,
+        None => 1.0,
     };
 
-    let capacity = match cmd_parts.get(4).map(|c| c.parse()) {
-        Some(Ok(capacity)) => capacity,
+    let capacity = match cmd_parts.get(4).map(|c| c.parse::<f64>()) {
+        Some(Ok(capacity)) if capacity.is_finite() => capacity,
+        Some(Ok(_)) => {
+            return Err("Пропускная способность ребра должна быть конечным числом".to_string());
+        }
         Some(Err(_)) => return Err("Пропускная способность ребра должна быть числом".to_string()),
-        None => 1,
+        None => 1.0,
     };
 
     match graph.add_edge(from, to, weight, capacity) {
-        Ok(_) => Ok(print_graph(&graph)),
-        Err(e) => match e {
-            GraphAddEdgeError::FromNodeDoesNotExist => {
-                Err("Начальная вершина не существует".to_string())
-            }
-            GraphAddEdgeError::ToNodeDoesNotExist => {
-                Err("Конечная вершина не существует".to_string())
-            }
-            GraphAddEdgeError::UndirectedGraph => Err("Граф неориентированный".to_string()),
-            GraphAddEdgeError::EdgeAlreadyExists => Err("Дуга уже существует".to_string()),
-        },
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
@@ -1,13 +1,13 @@
-use crate::{cli::print_graph::print_graph, graph::Graph};
+use sgu_graphs::{graph::Graph, label::Label};
 
-pub fn add_node_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
+use crate::cli::print_graph::print_graph;
+
+pub fn add_node_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
     let Some(value) = cmd_parts.get(1) else {
         return Err("Вы должны указать значение".to_string());
     };
-    let Ok(value) = value.trim().parse() else {
-        return Err("Значение должно быть числом".to_string());
-    };
+    let value = Label::from(value.trim());
     graph.add_node(value);
-    print_graph(&graph);
+    print_graph(graph);
     Ok(true)
 }
@@ -1,41 +1,31 @@
-use crate::{
-    cli::print_graph::print_graph,
-    graph::{Graph, GraphAddRibError},
-};
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
 
-pub fn add_rib_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn add_rib_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
     let Some(first) = cmd_parts.get(1) else {
         return Err("Вы должны указать первую вершину".to_string());
     };
-    let Ok(first) = first.trim().parse() else {
-        return Err("Первая вершина должна быть числом".to_string());
-    };
+    let first = Label::from(first.trim());
     let Some(second) = cmd_parts.get(2) else {
         return Err("Вы должны указать вторую вершину".to_string());
     };
-    let Ok(second) = second.trim().parse() else {
-        return Err("Вторая вершина должна быть числом".to_string());
-    };
+    let second = Label::from(second.trim());
     let Ok(weight) = cmd_parts.get(3).unwrap_or(&"1".to_string()).trim().parse() else {
-        return Err("Вес ребра должен быть целым числом".to_string());
+        return Err("Вес ребра должен быть числом".to_string());
     };
 
-    let capacity = match cmd_parts.get(4).map(|c| c.parse()) {
-        Some(Ok(capacity)) => capacity,
+    let capacity = match cmd_parts.get(4).map(|c| c.parse::<f64>()) {
+        Some(Ok(capacity)) if capacity.is_finite() => capacity,
+        Some(Ok(_)) => {
+            return Err("Пропускная способность ребра должна быть конечным числом".to_string());
+        }
         Some(Err(_)) => return Err("Пропускная способность ребра должна быть числом".to_string()),
-        None => 1,
+        None => 1.0,
     };
 
     match graph.add_rib(first, second, weight, capacity) {
-        Ok(_) => Ok(print_graph(&graph)),
-        Err(e) => match e {
-            GraphAddRibError::FirstNodeDoesNotExist => {
-                Err("Первая вершина не существует".to_string())
-            }
-            GraphAddRibError::SecondNodeDoesNotExist => {
-                Err("Вторая вершина не существует".to_string())
-            }
-            GraphAddRibError::RibAlreadyExists => Err("Ребро уже существует".to_string()),
-        },
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
@@ -0,0 +1,24 @@
+use sgu_graphs::{
+    algorithms::set_ops::complement, error::GraphError, files::load_graph, graph::Graph,
+    label::Label,
+};
+
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn complement_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    // Без аргументов берётся текущий граф, иначе граф из файла
+    let source = match cmd_parts.get(1) {
+        Some(path) => {
+            load_graph(path).map_err(|e| format!("Ошибка при загрузке графа {path}: {e}"))?
+        }
+        None => graph.clone(),
+    };
+
+    match complement(&source) {
+        Ok(result) => {
+            *graph = result;
+            Ok(print_graph(graph))
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
@@ -0,0 +1,17 @@
+use sgu_graphs::{algorithms::set_ops::difference, error::GraphError, graph::Graph, label::Label};
+
+use crate::cli::{
+    print_graph::print_graph, render_error::render_error, set_operands::set_operands,
+};
+
+pub fn difference_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let (first, second) = set_operands(cmd_parts, graph)?;
+
+    match difference(&first, &second) {
+        Ok(result) => {
+            *graph = result;
+            Ok(print_graph(graph))
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
@@ -0,0 +1,29 @@
+use sgu_graphs::{graph::Graph, label::Label};
+
+pub fn edge_attrs_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    let Some(from) = cmd_parts.get(1).map(|s| Label::from(s.as_str())) else {
+        return Err("Вы должны указать начальную вершину".to_string());
+    };
+    let Some(to) = cmd_parts.get(2).map(|s| Label::from(s.as_str())) else {
+        return Err("Вы должны указать конечную вершину".to_string());
+    };
+
+    if !graph.contains_node(&from) {
+        return Err("Начальная вершина не существует".to_string());
+    }
+    let mut edges = graph.out_edges(&from).filter(|e| e.node == to).peekable();
+    if edges.peek().is_none() {
+        return Err("Ребра или дуги между вершинами не существует".to_string());
+    }
+
+    for edge in edges {
+        match cmd_parts.get(3) {
+            Some(key) => match edge.attributes.get(key) {
+                Some(value) => println!("[{}] {key} = {value}", edge.id),
+                None => println!("[{}] нет свойства {key}", edge.id),
+            },
+            None => println!("[{}] {:#?}", edge.id, edge.attributes),
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,64 @@
+use sgu_graphs::{
+    algorithms::{dijkstra::shortest_path_tree, max_flow::max_flow, mst::mst_prim},
+    error::{Endpoint, GraphError},
+    files::dot::{Highlight, save_dot},
+    graph::Graph,
+    label::Label,
+};
+
+use crate::cli::render_error::render_error;
+
+/// `export_dot <путь> [mst <вершина> | path <вершина> | flow <исток> <сток>]` —
+/// сохраняет граф в формате DOT, выделяя остовное дерево, дерево кратчайших
+/// путей или насыщенные дуги максимального потока
+pub fn export_dot_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    let Some(path) = cmd_parts.get(1) else {
+        return Err("Вы должны указать путь для сохранения графа".to_string());
+    };
+
+    let node = |i: usize, what: &str| {
+        cmd_parts
+            .get(i)
+            .map(|s| Label::from(s.as_str()))
+            .ok_or(format!("Вы должны указать {what}"))
+    };
+    let highlight = match cmd_parts.get(2).map(String::as_str) {
+        None => Highlight::default(),
+        Some("mst") => {
+            let start = node(3, "начальную вершину")?;
+            let mst = mst_prim(graph, start).map_err(|e| render_error(GraphError::from(e)))?;
+            Highlight::subgraph(&mst)
+        }
+        Some("path") => {
+            let source = node(3, "начальную вершину")?;
+            let tree =
+                shortest_path_tree(graph, source).map_err(|e| render_error(GraphError::from(e)))?;
+            Highlight::arcs(tree.into_iter().map(|(node, parent)| (parent, node)))
+        }
+        Some("flow") => {
+            let source = node(3, "исток")?;
+            let sink = node(4, "сток")?;
+            // Без истока или стока поток нулевой, и файл сохранился бы без
+            // выделения
+            if let Some(missing) = [&source, &sink]
+                .into_iter()
+                .find(|node| !graph.contains_node(node))
+            {
+                return Err(render_error(GraphError::NodeNotFound {
+                    node: missing.clone(),
+                    role: Endpoint::Node,
+                }));
+            }
+            let flow = max_flow(graph, source, sink);
+            println!("Максимальный поток: {}", flow.value);
+            Highlight::arcs(flow.saturated)
+        }
+        Some(kind) => return Err(format!("Неизвестный вид выделения: {kind}")),
+    };
+
+    if let Err(e) = save_dot(graph, &highlight, path) {
+        return Err(format!("Ошибка при сохранении графа: {e}"));
+    }
+    println!("Граф сохранен в формате DOT в {}", path);
+    Ok(true)
+}
@@ -1,14 +1,15 @@
-use crate::{
-    graph::Graph,
-    tasks::task2::{IncomingNodesError, get_incoming_nodes},
+use sgu_graphs::{
+    algorithms::neighbours::get_incoming_nodes, error::GraphError, graph::Graph, label::Label,
 };
 
-pub fn in_degree_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
-    let Some(node) = cmd_parts.get(1).and_then(|s| s.parse().ok()) else {
+use crate::cli::render_error::render_error;
+
+pub fn in_degree_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    let Some(node) = cmd_parts.get(1).map(|s| Label::from(s.as_str())) else {
         return Err("Вы должны указать вершину".to_string());
     };
 
-    match get_incoming_nodes(graph, node) {
+    match get_incoming_nodes(graph, node.clone()) {
         Ok(incoming_nodes) => {
             println!(
                 "Полустепень захода вершины {} = {}",
@@ -17,12 +18,6 @@ pub fn in_degree_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, St
             );
             Ok(true)
         }
-        Err(e) => match e {
-            IncomingNodesError::NodeDoesNotExist => Err("Вершина не существует".to_string()),
-            IncomingNodesError::UndirectedGraph => Err(
-                "Граф неориентированный, получение полустепени захода не поддерживается"
-                    .to_string(),
-            ),
-        },
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
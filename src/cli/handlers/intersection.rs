@@ -0,0 +1,22 @@
+use sgu_graphs::{
+    algorithms::set_ops::intersection, error::GraphError, graph::Graph, label::Label,
+};
+
+use crate::cli::{
+    print_graph::print_graph, render_error::render_error, set_operands::weighted_operands,
+};
+
+pub fn intersection_cmd(
+    cmd_parts: &[String],
+    graph: &mut Graph<Label, f64>,
+) -> Result<bool, String> {
+    let (first, second, policy) = weighted_operands(cmd_parts, graph)?;
+
+    match intersection(&first, &second, policy) {
+        Ok(result) => {
+            *graph = result;
+            Ok(print_graph(graph))
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
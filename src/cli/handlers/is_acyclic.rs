@@ -1,9 +1,8 @@
-use crate::{
-    graph::Graph,
-    tasks::task6::{IsAcyclicError, is_acyclic},
-};
+use sgu_graphs::{algorithms::acyclic::is_acyclic, error::GraphError, graph::Graph, label::Label};
 
-pub fn is_acyclic_cmd(graph: &Graph) -> Result<bool, String> {
+use crate::cli::render_error::render_error;
+
+pub fn is_acyclic_cmd(graph: &Graph<Label, f64>) -> Result<bool, String> {
     match is_acyclic(graph) {
         Ok(true) => {
             println!("Граф ацикличен");
@@ -13,8 +12,6 @@ pub fn is_acyclic_cmd(graph: &Graph) -> Result<bool, String> {
             println!("Граф цикличен");
             Ok(true)
         }
-        Err(e) => match e {
-            IsAcyclicError::UndirectedGraph => Err("Граф неориентированный".to_string()),
-        },
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
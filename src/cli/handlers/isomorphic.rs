@@ -0,0 +1,51 @@
+use sgu_graphs::{
+    algorithms::isomorphism::{Compare, isomorphism, subgraph_isomorphism},
+    files::load_graph,
+    graph::Graph,
+    label::Label,
+};
+
+/// `isomorphic <путь> [weights] [attributes] [subgraph]` — сравнивает текущий
+/// граф с графом из файла и выводит соответствие вершин. С `subgraph` ищет
+/// текущий граф как подграф графа из файла.
+pub fn isomorphic_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    let Some(path) = cmd_parts.get(1) else {
+        return Err("Вы должны указать путь к графу для сравнения".to_string());
+    };
+
+    let mut compare = Compare::default();
+    let mut subgraph = false;
+    for option in &cmd_parts[2..] {
+        match option.as_str() {
+            "weights" => compare.weights = true,
+            "attributes" => compare.attributes = true,
+            "subgraph" => subgraph = true,
+            _ => return Err(format!("Неизвестный параметр: {option}")),
+        }
+    }
+
+    let other: Graph<Label, f64> =
+        load_graph(path).map_err(|e| format!("Ошибка при загрузке графа {path}: {e}"))?;
+
+    let mapping = if subgraph {
+        subgraph_isomorphism(graph, &other, compare)
+    } else {
+        isomorphism(graph, &other, compare)
+    };
+
+    match mapping {
+        Some(mapping) => {
+            if subgraph {
+                println!("Граф содержится в графе из файла:");
+            } else {
+                println!("Графы изоморфны:");
+            }
+            for (from, to) in mapping {
+                println!("  {from} -> {to}");
+            }
+        }
+        None if subgraph => println!("Граф не содержится в графе из файла"),
+        None => println!("Графы не изоморфны"),
+    }
+    Ok(true)
+}
@@ -0,0 +1,27 @@
+use sgu_graphs::{
+    algorithms::products::line_graph, error::GraphError, graph::Graph, label::Label,
+    weight::WeightPolicy,
+};
+
+use crate::cli::{
+    parse_policy::parse_policy, print_graph::print_graph, render_error::render_error,
+};
+
+pub fn line_graph_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let policy = match cmd_parts.get(1) {
+        Some(raw) => parse_policy(raw).ok_or(format!("Неизвестная политика весов: {raw}"))?,
+        None => WeightPolicy::default(),
+    };
+
+    match line_graph(graph, policy) {
+        Ok(result) => {
+            // Ребро u - v получает метку "u-v", у кратных рёбер добавляется идентификатор
+            *graph = result.map_nodes(|(u, v, id)| match id {
+                0 => Label::from(format!("{u}-{v}").as_str()),
+                id => Label::from(format!("{u}-{v}#{id}").as_str()),
+            });
+            Ok(print_graph(graph))
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
@@ -0,0 +1,58 @@
+use sgu_graphs::{
+    error::GraphError,
+    files::{
+        edge_list::load_edge_list,
+        load_graph_unchecked,
+        matrix::{load_adjacency_matrix, load_incidence_matrix},
+    },
+    graph::Graph,
+    label::Label,
+    validate::{SelfLoops, repair, validate},
+};
+
+use crate::cli::{
+    edge_list_options::edge_list_options, handlers::validate::print_problems,
+    matrix_options::matrix_options, print_graph::print_graph, render_error::render_error,
+};
+
+/// `load <путь> [csv directed|undirected [delimiter <символ>] [header|no_header]
+/// | matrix [directed|undirected] [no_edge <обозначение>] | incidence] [repair]` —
+/// заменяет текущий граф графом из файла. Без формата он выбирается по
+/// расширению: JSON, DOT, GraphML или GEXF. Граф с ошибками в структуре не
+/// загружается, а с `repair` исправляется так же, как командой `validate repair`.
+pub fn load_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let Some(path) = cmd_parts.get(1) else {
+        return Err("Вы должны указать путь к файлу".to_string());
+    };
+
+    let fix = cmd_parts.len() > 2 && cmd_parts.last().is_some_and(|part| part == "repair");
+    let options = &cmd_parts[2..cmd_parts.len() - usize::from(fix)];
+
+    let loaded = match options.first().map(String::as_str) {
+        None => load_graph_unchecked(path),
+        Some("csv") => load_edge_list(path, &edge_list_options(&options[1..], None)?),
+        Some("matrix") => load_adjacency_matrix(path, &matrix_options(&options[1..])?),
+        Some("incidence") => load_incidence_matrix(path),
+        Some(format) => return Err(format!("Неизвестный формат {format}")),
+    };
+    let mut loaded = loaded.map_err(|e| format!("Ошибка при загрузке графа {path}: {e}"))?;
+
+    if fix {
+        let repaired = repair(&mut loaded, SelfLoops::Allow);
+        if !repaired.is_empty() {
+            println!("Исправлено проблем: {}", repaired.len());
+            print_problems(&repaired);
+        }
+    } else {
+        let problems = validate(&loaded, SelfLoops::Allow);
+        if !problems.is_empty() {
+            return Err(format!(
+                "Граф {path} не загружен. {}\nЧтобы исправить граф при загрузке, добавьте параметр repair",
+                render_error(GraphError::InvalidStructure(problems))
+            ));
+        }
+    }
+    *graph = loaded;
+
+    Ok(print_graph(graph))
+}
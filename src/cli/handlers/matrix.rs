@@ -0,0 +1,22 @@
+use sgu_graphs::{
+    files::matrix::{to_adjacency_matrix, to_incidence_matrix},
+    graph::Graph,
+    label::Label,
+};
+
+use crate::cli::matrix_options::matrix_options;
+
+/// `matrix [incidence | no_edge <обозначение>]` — выводит граф матрицей весов
+/// или матрицей инцидентности
+pub fn matrix_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    if cmd_parts.get(1).map(String::as_str) == Some("incidence") {
+        print!("{}", to_incidence_matrix(graph));
+        return Ok(true);
+    }
+
+    let options = matrix_options(&cmd_parts[1..])?;
+    let matrix = to_adjacency_matrix(graph, &options)
+        .map_err(|e| format!("Невозможно вывести матрицу смежности: {e}"))?;
+    print!("{matrix}");
+    Ok(true)
+}
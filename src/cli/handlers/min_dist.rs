@@ -1,21 +1,15 @@
-use crate::{
-    graph::Graph,
-    tasks::task9::{MinDistanceError, min_distance},
+use sgu_graphs::{
+    algorithms::bellman_ford::min_distance, error::GraphError, graph::Graph, label::Label,
 };
 
-pub fn min_dist_cmd(graph: &Graph) -> Result<bool, String> {
+use crate::cli::render_error::render_error;
+
+pub fn min_dist_cmd(graph: &Graph<Label, f64>) -> Result<bool, String> {
     match min_distance(graph) {
         Ok((node, distance)) => {
             println!("Вершина {node} с минимальной суммой расстояний до других вершин {distance}");
             Ok(true)
         }
-        Err(e) => match e {
-            MinDistanceError::NegativeCycle => {
-                Err("Граф содержит цикл с отрицательным весом".to_string())
-            }
-            MinDistanceError::EmptyGraph => {
-                Err("Невозможно запустить для пустого графа".to_string())
-            }
-        },
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
@@ -1,17 +1,38 @@
 pub mod add_arc;
 pub mod add_node;
 pub mod add_rib;
+pub mod complement;
+pub mod difference;
+pub mod edge_attrs;
+pub mod export_dot;
 pub mod ford_fulkerson;
 pub mod in_degree;
+pub mod intersection;
 pub mod is_acyclic;
+pub mod isomorphic;
+pub mod line_graph;
+pub mod load;
+pub mod matrix;
 pub mod min_dist;
 pub mod min_len;
 pub mod mst_prim;
 pub mod n_per;
+pub mod node_attrs;
 pub mod node_with_greater_outdegree;
 pub mod out_degree;
+pub mod power;
+pub mod product;
 pub mod remove_arc;
 pub mod remove_node;
 pub mod remove_rib;
+pub mod reverse;
 pub mod save;
+pub mod set_capacity;
+pub mod set_edge_attr;
+pub mod set_node_attr;
+pub mod set_weight;
 pub mod sym_diff;
+pub mod to_directed;
+pub mod to_undirected;
+pub mod union;
+pub mod validate;
@@ -0,0 +1,27 @@
+use sgu_graphs::{graph::Graph, label::Label};
+
+pub fn node_attrs_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    let Some(node) = cmd_parts.get(1).map(|s| Label::from(s.as_str())) else {
+        return Err("Вы должны указать вершину".to_string());
+    };
+
+    if !graph.contains_node(&node) {
+        return Err("Вершина не существует".to_string());
+    }
+
+    match cmd_parts.get(2) {
+        Some(key) => match graph.node_attribute(&node, key) {
+            Some(value) => println!("{key} = {value}"),
+            None => return Err(format!("У вершины {node} нет свойства {key}")),
+        },
+        None => println!(
+            "{:#?}",
+            graph
+                .node_attributes
+                .get(&node)
+                .cloned()
+                .unwrap_or_default()
+        ),
+    }
+    Ok(true)
+}
@@ -1,16 +1,18 @@
-use crate::{
-    graph::Graph,
-    tasks::task3::{NodesWithGreaterOutdegreeError, get_nodes_with_greater_outdegree},
+use sgu_graphs::{
+    algorithms::outdegree::get_nodes_with_greater_outdegree, error::GraphError, graph::Graph,
+    label::Label,
 };
 
+use crate::cli::render_error::render_error;
+
 pub fn node_with_greater_outdegree_cmd(
     cmd_parts: &[String],
-    graph: &mut Graph,
+    graph: &Graph<Label, f64>,
 ) -> Result<bool, String> {
-    let Some(node) = cmd_parts.get(1).and_then(|s| s.parse().ok()) else {
+    let Some(node) = cmd_parts.get(1).map(|s| Label::from(s.as_str())) else {
         return Err("Вы должны указать вершину".to_string());
     };
-    match get_nodes_with_greater_outdegree(graph, node) {
+    match get_nodes_with_greater_outdegree(graph, node.clone()) {
         Ok(nodes) => {
             println!(
                 "Вершины, у которых полустепень захода больше, чем у {} = {:?}",
@@ -18,13 +20,6 @@ pub fn node_with_greater_outdegree_cmd(
             );
             Ok(true)
         }
-        Err(e) => match e {
-            NodesWithGreaterOutdegreeError::NodeDoesNotExist => {
-                Err("Вершина не существует".to_string())
-            }
-            NodesWithGreaterOutdegreeError::UndirectedGraph => {
-                Err("Граф должен быть ориентированным".to_string())
-            }
-        },
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
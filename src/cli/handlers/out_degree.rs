@@ -1,24 +1,19 @@
-use crate::{
-    graph::Graph,
-    tasks::task2::{OutgoingNodesError, get_outgoing_nodes},
+use sgu_graphs::{
+    algorithms::neighbours::get_outgoing_nodes, error::GraphError, graph::Graph, label::Label,
 };
 
-pub fn out_degree_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
-    let Some(node) = cmd_parts.get(1).and_then(|s| s.parse().ok()) else {
+use crate::cli::render_error::render_error;
+
+pub fn out_degree_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
+    let Some(node) = cmd_parts.get(1).map(|s| Label::from(s.as_str())) else {
         return Err("Вы должны указать вершину".to_string());
     };
 
-    match get_outgoing_nodes(graph, node) {
+    match get_outgoing_nodes(graph, node.clone()) {
         Ok(nodes) => {
             println!("Полустепень исхода вершины {} = {}", node, nodes.len());
             Ok(true)
         }
-        Err(e) => match e {
-            OutgoingNodesError::NodeDoesNotExist => Err("Вершина не существует".to_string()),
-            OutgoingNodesError::UndirectedGraph => Err(
-                "Граф неориентированный, получение полустепени исхода не поддерживается"
-                    .to_string(),
-            ),
-        },
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
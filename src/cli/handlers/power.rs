@@ -0,0 +1,28 @@
+use sgu_graphs::{
+    algorithms::products::power, error::GraphError, graph::Graph, label::Label,
+    weight::WeightPolicy,
+};
+
+use crate::cli::{
+    parse_policy::parse_policy, print_graph::print_graph, render_error::render_error,
+};
+
+pub fn power_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let k = match cmd_parts.get(1).map(|k| k.parse()) {
+        Some(Ok(k)) => k,
+        Some(Err(_)) => return Err("Степень должна быть неотрицательным целым числом".to_string()),
+        None => return Err("Вы должны указать степень".to_string()),
+    };
+    let policy = match cmd_parts.get(2) {
+        Some(raw) => parse_policy(raw).ok_or(format!("Неизвестная политика весов: {raw}"))?,
+        None => WeightPolicy::Sum,
+    };
+
+    match power(graph, k, policy) {
+        Ok(result) => {
+            *graph = result;
+            Ok(print_graph(graph))
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
@@ -0,0 +1,37 @@
+use sgu_graphs::{
+    algorithms::products::{Product, product},
+    error::GraphError,
+    graph::Graph,
+    label::Label,
+};
+
+use crate::cli::{
+    print_graph::print_graph, render_error::render_error, set_operands::weighted_operands,
+};
+
+pub fn product_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let kind = match cmd_parts.get(1).map(String::as_str) {
+        Some("cartesian") => Product::Cartesian,
+        Some("tensor") => Product::Tensor,
+        Some("strong") => Product::Strong,
+        Some("lexicographic") => Product::Lexicographic,
+        Some(kind) => return Err(format!("Неизвестный вид произведения: {kind}")),
+        None => {
+            return Err(
+                "Вы должны указать вид произведения: cartesian, tensor, strong или lexicographic"
+                    .to_string(),
+            );
+        }
+    };
+    // Вид произведения занимает место имени команды для разбора операндов
+    let (first, second, policy) = weighted_operands(&cmd_parts[1..], graph)?;
+
+    match product(&first, &second, kind, policy) {
+        Ok(result) => {
+            // Вершина-пара (u, v) получает метку "u,v"
+            *graph = result.map_nodes(|(u, v)| Label::from(format!("{u},{v}").as_str()));
+            Ok(print_graph(graph))
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
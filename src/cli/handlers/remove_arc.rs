@@ -1,37 +1,25 @@
-use crate::{
-    cli::print_graph::print_graph,
-    graph::{Graph, GraphRemoveEdgeError},
-};
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
 
-pub fn remove_arc_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn remove_arc_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
     let Some(from) = cmd_parts.get(1) else {
         return Err("Вы должны указать начальную вершину".to_string());
     };
-    let Ok(from) = from.trim().parse() else {
-        return Err("Начальная вершина должна быть числом".to_string());
-    };
+    let from = Label::from(from.trim());
     let Some(to) = cmd_parts.get(2) else {
         return Err("Вы должны указать конечную вершину".to_string());
     };
-    let Ok(to) = to.trim().parse() else {
-        return Err("Конечная вершина должна быть числом".to_string());
+    let to = Label::from(to.trim());
+
+    let result = match cmd_parts.get(3).map(|id| id.parse()) {
+        Some(Ok(id)) => graph.remove_edge_with_id(from, to, id),
+        Some(Err(_)) => return Err("Идентификатор дуги должен быть числом".to_string()),
+        None => graph.remove_edge(from, to),
     };
 
-    match graph.remove_edge(from, to) {
-        Ok(_) => Ok(print_graph(&graph)),
-        Err(e) => match e {
-            GraphRemoveEdgeError::FromNodeDoesNotExist => {
-                Err("Начальная вершина не существует".to_string())
-            }
-            GraphRemoveEdgeError::ToNodeDoesNotExist => {
-                Err("Конечная вершина не существует".to_string())
-            }
-            GraphRemoveEdgeError::UndirectedGraph => {
-                Err("Граф неориентированный, удаление дуг не поддерживается".to_string())
-            }
-            GraphRemoveEdgeError::EdgeDoesNotExist => {
-                Err("Дуги между вершинами не существует".to_string())
-            }
-        },
+    match result {
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
@@ -1,34 +1,25 @@
-use crate::{
-    cli::print_graph::print_graph,
-    graph::{Graph, GraphRemoveRibError},
-};
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
 
-pub fn remove_rib_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn remove_rib_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
     let Some(first) = cmd_parts.get(1) else {
         return Err("Вы должны указать первую вершину".to_string());
     };
-    let Ok(first) = first.trim().parse() else {
-        return Err("Первая вершина должна быть числом".to_string());
-    };
+    let first = Label::from(first.trim());
     let Some(second) = cmd_parts.get(2) else {
         return Err("Вы должны указать вторую вершину".to_string());
     };
-    let Ok(second) = second.trim().parse() else {
-        return Err("Вторая вершина должна быть числом".to_string());
+    let second = Label::from(second.trim());
+
+    let result = match cmd_parts.get(3).map(|id| id.parse()) {
+        Some(Ok(id)) => graph.remove_rib_with_id(first, second, id),
+        Some(Err(_)) => return Err("Идентификатор ребра должен быть числом".to_string()),
+        None => graph.remove_rib(first, second),
     };
 
-    match graph.remove_rib(first, second) {
-        Ok(_) => Ok(print_graph(&graph)),
-        Err(e) => match e {
-            GraphRemoveRibError::FirstNodeDoesNotExist => {
-                Err("Первая вершина не существует".to_string())
-            }
-            GraphRemoveRibError::SecondNodeDoesNotExist => {
-                Err("Вторая вершина не существует".to_string())
-            }
-            GraphRemoveRibError::RibDoesNotExist => {
-                Err("Ребра между вершинами не существует".to_string())
-            }
-        },
+    match result {
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
     }
 }
@@ -0,0 +1,12 @@
+use sgu_graphs::{graph::Graph, label::Label};
+
+use crate::cli::replace_or_fork::replace_or_fork;
+
+pub fn reverse_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    if !graph.directed {
+        return Err("Граф неориентированный, эта команда не поддерживается".to_string());
+    }
+
+    let reversed = graph.reversed();
+    replace_or_fork(graph, reversed, cmd_parts.get(1))
+}
@@ -1,10 +1,34 @@
-use crate::{files::save_graph, graph::Graph};
+use sgu_graphs::{
+    files::{
+        edge_list::save_edge_list,
+        matrix::{save_adjacency_matrix, save_incidence_matrix},
+        save_graph,
+    },
+    graph::Graph,
+    label::Label,
+};
 
-pub fn save_cmd(cmd_parts: &[String], graph: &mut Graph) -> Result<bool, String> {
+use crate::cli::{edge_list_options::edge_list_options, matrix_options::matrix_options};
+
+/// `save <путь> [csv [delimiter <символ>] [no_header] | matrix [no_edge
+/// <обозначение>] | incidence]` — сохраняет граф. Без формата он выбирается
+/// по расширению: DOT, GraphML, GEXF или JSON.
+pub fn save_cmd(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<bool, String> {
     let Some(path) = cmd_parts.get(1) else {
         return Err("Вы должны указать путь для сохранения графа".to_string());
     };
-    if let Err(e) = save_graph(graph, path) {
+
+    let saved = match cmd_parts.get(2).map(String::as_str) {
+        None => save_graph(graph, path),
+        Some("csv") => {
+            let options = edge_list_options(&cmd_parts[3..], Some(graph.directed))?;
+            save_edge_list(graph, path, &options)
+        }
+        Some("matrix") => save_adjacency_matrix(graph, path, &matrix_options(&cmd_parts[3..])?),
+        Some("incidence") => save_incidence_matrix(graph, path),
+        Some(format) => return Err(format!("Неизвестный формат {format}")),
+    };
+    if let Err(e) = saved {
         return Err(format!("Ошибка при сохранении графа: {e}"));
     }
     println!("Граф успешно сохранен в {}", path);
@@ -0,0 +1,31 @@
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
+
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn set_capacity_cmd(
+    cmd_parts: &[String],
+    graph: &mut Graph<Label, f64>,
+) -> Result<bool, String> {
+    let Some(from) = cmd_parts.get(1) else {
+        return Err("Вы должны указать начальную вершину".to_string());
+    };
+    let from = Label::from(from.trim());
+    let Some(to) = cmd_parts.get(2) else {
+        return Err("Вы должны указать конечную вершину".to_string());
+    };
+    let to = Label::from(to.trim());
+
+    let capacity = match cmd_parts.get(3).map(|c| c.parse::<f64>()) {
+        Some(Ok(capacity)) if capacity.is_finite() => capacity,
+        Some(Ok(_)) => {
+            return Err("Пропускная способность ребра должна быть конечным числом".to_string());
+        }
+        Some(Err(_)) => return Err("Пропускная способность ребра должна быть числом".to_string()),
+        None => return Err("Вы должны указать пропускную способность".to_string()),
+    };
+
+    match graph.set_capacity(from, to, capacity) {
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
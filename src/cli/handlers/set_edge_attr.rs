@@ -0,0 +1,29 @@
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
+
+use crate::cli::{parse_value::parse_value, print_graph::print_graph, render_error::render_error};
+
+pub fn set_edge_attr_cmd(
+    cmd_parts: &[String],
+    graph: &mut Graph<Label, f64>,
+) -> Result<bool, String> {
+    let Some(from) = cmd_parts.get(1) else {
+        return Err("Вы должны указать начальную вершину".to_string());
+    };
+    let from = Label::from(from.trim());
+    let Some(to) = cmd_parts.get(2) else {
+        return Err("Вы должны указать конечную вершину".to_string());
+    };
+    let to = Label::from(to.trim());
+    let Some(key) = cmd_parts.get(3) else {
+        return Err("Вы должны указать название свойства".to_string());
+    };
+    if cmd_parts.len() < 5 {
+        return Err("Вы должны указать значение свойства".to_string());
+    }
+    let value = parse_value(&cmd_parts[4..]);
+
+    match graph.set_edge_attribute(from, to, key, value) {
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
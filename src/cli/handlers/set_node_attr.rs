@@ -0,0 +1,28 @@
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
+
+use crate::cli::{parse_value::parse_value, render_error::render_error};
+
+pub fn set_node_attr_cmd(
+    cmd_parts: &[String],
+    graph: &mut Graph<Label, f64>,
+) -> Result<bool, String> {
+    let Some(node) = cmd_parts.get(1) else {
+        return Err("Вы должны указать вершину".to_string());
+    };
+    let node = Label::from(node.trim());
+    let Some(key) = cmd_parts.get(2) else {
+        return Err("Вы должны указать название свойства".to_string());
+    };
+    if cmd_parts.len() < 4 {
+        return Err("Вы должны указать значение свойства".to_string());
+    }
+    let value = parse_value(&cmd_parts[3..]);
+
+    match graph.set_node_attribute(node.clone(), key, value) {
+        Ok(_) => {
+            println!("{:#?}", graph.node_attributes[&node]);
+            Ok(true)
+        }
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
@@ -0,0 +1,25 @@
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
+
+use crate::cli::{print_graph::print_graph, render_error::render_error};
+
+pub fn set_weight_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let Some(from) = cmd_parts.get(1) else {
+        return Err("Вы должны указать начальную вершину".to_string());
+    };
+    let from = Label::from(from.trim());
+    let Some(to) = cmd_parts.get(2) else {
+        return Err("Вы должны указать конечную вершину".to_string());
+    };
+    let to = Label::from(to.trim());
+
+    let weight = match cmd_parts.get(3).map(|w| w.parse()) {
+        Some(Ok(weight)) => weight,
+        Some(Err(_)) => return Err("Вес должен быть числом".to_string()),
+        None => return Err("Вы должны указать вес".to_string()),
+    };
+
+    match graph.set_weight(from, to, weight) {
+        Ok(_) => Ok(print_graph(graph)),
+        Err(e) => Err(render_error(GraphError::from(e))),
+    }
+}
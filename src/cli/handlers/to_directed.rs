@@ -0,0 +1,15 @@
+use sgu_graphs::{graph::Graph, label::Label};
+
+use crate::cli::replace_or_fork::replace_or_fork;
+
+pub fn to_directed_cmd(
+    cmd_parts: &[String],
+    graph: &mut Graph<Label, f64>,
+) -> Result<bool, String> {
+    if graph.directed && !graph.mixed {
+        return Err("Граф уже ориентированный".to_string());
+    }
+
+    let directed = graph.to_directed();
+    replace_or_fork(graph, directed, cmd_parts.get(1))
+}
@@ -0,0 +1,25 @@
+use sgu_graphs::{graph::Graph, label::Label, weight::WeightPolicy};
+
+use crate::cli::{parse_policy::parse_policy, replace_or_fork::replace_or_fork};
+
+pub fn to_undirected_cmd(
+    cmd_parts: &[String],
+    graph: &mut Graph<Label, f64>,
+) -> Result<bool, String> {
+    if !graph.directed {
+        return Err("Граф уже неориентированный".to_string());
+    }
+
+    // Политика необязательна: `to_undirected [min|max|sum|left|right] [путь]`
+    let mut args = cmd_parts[1..].iter().peekable();
+    let policy = match args.peek().and_then(|raw| parse_policy(raw)) {
+        Some(policy) => {
+            args.next();
+            policy
+        }
+        None => WeightPolicy::default(),
+    };
+
+    let undirected = graph.to_undirected(policy);
+    replace_or_fork(graph, undirected, args.next())
+}
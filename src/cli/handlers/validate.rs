@@ -0,0 +1,65 @@
+use sgu_graphs::{
+    graph::Graph,
+    label::Label,
+    validate::{Problem, SelfLoops, repair, validate},
+};
+
+use crate::cli::print_graph::print_graph;
+
+pub fn validate_cmd(cmd_parts: &[String], graph: &mut Graph<Label, f64>) -> Result<bool, String> {
+    let mut fix = false;
+    let mut self_loops = SelfLoops::Allow;
+    for option in &cmd_parts[1..] {
+        match option.as_str() {
+            "repair" => fix = true,
+            "no_loops" => self_loops = SelfLoops::Forbid,
+            _ => return Err(format!("Неизвестный параметр: {option}")),
+        }
+    }
+
+    if !fix {
+        let problems = validate(graph, self_loops);
+        if problems.is_empty() {
+            println!("Проблем в структуре графа не найдено");
+        } else {
+            println!("Найдено проблем: {}", problems.len());
+            print_problems(&problems);
+        }
+        return Ok(true);
+    }
+
+    let repaired = repair(graph, self_loops);
+    if repaired.is_empty() {
+        println!("Проблем в структуре графа не найдено");
+        return Ok(true);
+    }
+    println!("Исправлено проблем: {}", repaired.len());
+    print_problems(&repaired);
+    Ok(print_graph(graph))
+}
+
+pub fn print_problems(problems: &[Problem<Label, f64>]) {
+    for problem in problems {
+        println!("  {}", describe_problem(problem));
+    }
+}
+
+pub fn describe_problem(problem: &Problem<Label, f64>) -> String {
+    match problem {
+        Problem::DanglingTarget { from, to, id } => {
+            format!("Дуга {from} -> {to} (id {id}) ведёт в несуществующую вершину")
+        }
+        Problem::AsymmetricRib { from, to, id } => {
+            format!("У ребра {from} - {to} (id {id}) нет обратной половины")
+        }
+        Problem::SelfLoop { node, id } => format!("Петля в вершине {node} (id {id})"),
+        Problem::NegativeCapacity {
+            from,
+            to,
+            id,
+            capacity,
+        } => format!(
+            "Дуга {from} -> {to} (id {id}) имеет отрицательную или не конечную пропускную способность {capacity}"
+        ),
+    }
+}
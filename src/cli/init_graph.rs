@@ -1,31 +1,57 @@
 use std::io::Write;
 
-use crate::{
-    cli::{ask_bool::ask_bool, print_graph::print_graph},
-    files::load_graph,
+use sgu_graphs::{
+    files::load_graph_unchecked,
     graph::Graph,
+    label::Label,
+    validate::{SelfLoops, repair, validate},
 };
 
-pub fn init_graph() -> Graph {
+use crate::cli::{
+    ask_bool::ask_bool, handlers::validate::print_problems, print_graph::print_graph,
+};
+
+/// Начальный граф сессии и команда load, которая воспроизводит его, если
+/// граф загружен из файла
+pub fn init_graph() -> (Graph<Label, f64>, Option<Vec<String>>) {
     let load_from_file = ask_bool("Загрузить граф из файла");
     if load_from_file {
         loop {
-            print!("Введите путь к файлу: ");
+            print!("Введите путь к файлу (JSON, DOT, GraphML или GEXF): ");
             std::io::stdout().flush().unwrap();
 
             let mut input = String::new();
             std::io::stdin().read_line(&mut input).unwrap();
 
             let path = input.trim();
-            match load_graph(path) {
-                Ok(graph) => {
+            match load_graph_unchecked(path) {
+                Ok(mut graph) => {
+                    let mut start = vec!["load".to_string(), path.to_string()];
+                    let problems = validate(&graph, SelfLoops::Allow);
+                    if !problems.is_empty() {
+                        println!("В структуре графа найдены проблемы:");
+                        print_problems(&problems);
+                        if !ask_bool("Исправить граф автоматически") {
+                            continue;
+                        }
+                        repair(&mut graph, SelfLoops::Allow);
+                        start.push("repair".to_string());
+                    }
                     print_graph(&graph);
-                    break graph;
+                    break (graph, Some(start));
                 }
                 Err(e) => eprintln!("Ошибка загрузки графа: {}", e),
             };
         }
     } else {
-        Graph::new(ask_bool("Будет ли граф ориентированным"))
+        let directed = ask_bool("Будет ли граф ориентированным");
+        let mut graph = if directed && ask_bool("Будет ли граф смешанным (дуги и рёбра)")
+        {
+            Graph::new_mixed()
+        } else {
+            Graph::new(directed)
+        };
+        graph.multigraph = ask_bool("Разрешить кратные рёбра");
+        (graph, None)
     }
 }
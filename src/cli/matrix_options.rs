@@ -0,0 +1,28 @@
+use sgu_graphs::files::matrix::Matrix;
+
+/// Разбирает настройки матрицы смежности: `directed` или `undirected` и
+/// `no_edge <обозначение>` (по умолчанию `-`)
+pub fn matrix_options(options: &[String]) -> Result<Matrix, String> {
+    let mut directed = None;
+    let mut no_edge = None;
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "directed" => directed = Some(true),
+            "undirected" => directed = Some(false),
+            "no_edge" => {
+                let marker = options
+                    .next()
+                    .ok_or("Вы должны указать обозначение после no_edge")?;
+                no_edge = Some(marker.clone());
+            }
+            other => return Err(format!("Неизвестная настройка матрицы: {other}")),
+        }
+    }
+
+    Ok(Matrix::builder()
+        .maybe_no_edge(no_edge)
+        .maybe_directed(directed)
+        .build())
+}
@@ -1,6 +1,15 @@
 pub mod ask_bool;
 pub mod cmd_loop;
 pub mod dispatch_cmd;
+pub mod edge_list_options;
 pub mod handlers;
 pub mod init_graph;
+pub mod matrix_options;
+pub mod parse_policy;
+pub mod parse_value;
 pub mod print_graph;
+pub mod render_error;
+pub mod replace_or_fork;
+pub mod session;
+pub mod set_operands;
+pub mod weight_source;
@@ -0,0 +1,13 @@
+use sgu_graphs::weight::WeightPolicy;
+
+/// Разбирает политику объединения весов: `min`, `max`, `sum`, `left`, `right`
+pub fn parse_policy(raw: &str) -> Option<WeightPolicy> {
+    match raw {
+        "min" => Some(WeightPolicy::Min),
+        "max" => Some(WeightPolicy::Max),
+        "sum" => Some(WeightPolicy::Sum),
+        "left" => Some(WeightPolicy::Left),
+        "right" => Some(WeightPolicy::Right),
+        _ => None,
+    }
+}
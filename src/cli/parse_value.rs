@@ -0,0 +1,8 @@
+use serde_json::Value;
+
+/// Разбирает значение свойства: корректный JSON (числа, `true`, массивы...)
+/// сохраняется как есть, всё остальное — как строка.
+pub fn parse_value(parts: &[String]) -> Value {
+    let raw = parts.join(" ");
+    serde_json::from_str(&raw).unwrap_or(Value::String(raw))
+}
@@ -1,6 +1,6 @@
-use crate::graph::Graph;
+use sgu_graphs::{graph::Graph, label::Label};
 
-pub fn print_graph(graph: &Graph) -> bool {
+pub fn print_graph(graph: &Graph<Label, f64>) -> bool {
     println!("{graph:#?}");
     true
 }
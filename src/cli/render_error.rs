@@ -0,0 +1,65 @@
+use sgu_graphs::{
+    error::{Endpoint, GraphError},
+    label::Label,
+};
+
+use crate::cli::handlers::validate::describe_problem;
+
+/// Сообщение об ошибке операции над графом для пользователя
+pub fn render_error(e: GraphError<Label, f64>) -> String {
+    match e {
+        GraphError::NodeNotFound { node, role } => {
+            let role = match role {
+                Endpoint::Node => "Вершина",
+                Endpoint::From => "Начальная вершина",
+                Endpoint::To => "Конечная вершина",
+            };
+            format!("{role} {node} не существует")
+        }
+        GraphError::EdgeNotFound { from, to, id } => {
+            let id = id
+                .map(|id| format!(" с идентификатором {id}"))
+                .unwrap_or_default();
+            format!("Ребра или дуги{id} между вершинами {from} и {to} не существует")
+        }
+        GraphError::EdgeAlreadyExists { from, to } => {
+            format!("Ребро или дуга {from} -> {to} уже существует")
+        }
+        GraphError::UndirectedGraph => {
+            "Граф неориентированный, эта команда не поддерживается".to_string()
+        }
+        GraphError::DirectedGraph => "Граф должен быть неориентированным".to_string(),
+        GraphError::MixedGraph => "Смешанные графы не поддерживаются".to_string(),
+        GraphError::DirectednessMismatch => {
+            "Графы должны быть одновременно ориентированными или неориентированными".to_string()
+        }
+        GraphError::NegativeWeight { from, to, weight } => {
+            format!("Ребро ({from}, {to}) имеет отрицательный вес {weight}")
+        }
+        GraphError::NegativeCapacity { from, to } => {
+            format!(
+                "Пропускная способность ребра ({from}, {to}) должна быть конечной и неотрицательной"
+            )
+        }
+        GraphError::NegativeCycle => "Граф содержит цикл с отрицательным весом".to_string(),
+        GraphError::EmptyGraph => "Невозможно запустить для пустого графа".to_string(),
+        GraphError::MissingAttribute(key) => format!("Не у всех рёбер есть свойство {key}"),
+        GraphError::InvalidWeight(key) => {
+            format!("Свойство {key} должно быть числом у всех рёбер")
+        }
+        GraphError::InvalidStructure(problems) => {
+            let mut message = format!("Найдено проблем в структуре графа: {}", problems.len());
+            for problem in &problems {
+                message.push_str(&format!("\n  {}", describe_problem(problem)));
+            }
+            message
+        }
+        GraphError::Batch(failures) => {
+            let mut message = format!("Не выполнено операций: {}", failures.len());
+            for (index, e) in failures {
+                message.push_str(&format!("\n  {}. {}", index + 1, render_error(e)));
+            }
+            message
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use sgu_graphs::{files::save_graph, graph::Graph, label::Label};
+
+use crate::cli::print_graph::print_graph;
+
+/// Заменяет текущий граф результатом операции или, если указан путь,
+/// сохраняет результат в файл, оставляя текущий граф без изменений.
+pub fn replace_or_fork(
+    graph: &mut Graph<Label, f64>,
+    result: Graph<Label, f64>,
+    path: Option<&String>,
+) -> Result<bool, String> {
+    let Some(path) = path else {
+        *graph = result;
+        return Ok(print_graph(graph));
+    };
+
+    if let Err(e) = save_graph(&result, path) {
+        return Err(format!("Ошибка при сохранении графа: {e}"));
+    }
+    println!("Результат сохранен в {}", path);
+    Ok(true)
+}
@@ -0,0 +1,385 @@
+use std::{fs, path::PathBuf};
+
+use sgu_graphs::{graph::Graph, label::Label};
+
+use crate::cli::{
+    dispatch_cmd::{dispatch_cmd, mutates_graph},
+    print_graph::print_graph,
+};
+
+/// Сколько последних команд можно отменить. Для более давних команд
+/// хранится только текст, чтобы их можно было сохранить командой `history`.
+const UNDO_LIMIT: usize = 100;
+
+// Выполненная команда вместе с графом, каким он был до неё. Снимок графа
+// есть только у последних UNDO_LIMIT команд.
+struct Entry {
+    command: Vec<String>,
+    before: Option<Graph<Label, f64>>,
+}
+
+/// Интерактивная сессия: текущий граф и история изменивших его команд
+pub struct Session {
+    graph: Graph<Label, f64>,
+    // Команда load, воспроизводящая начальный граф, если он загружен из файла
+    start: Option<Vec<String>>,
+    done: Vec<Entry>,
+    undone: Vec<Entry>,
+    // Выполняемые сейчас сценарии, чтобы сценарий не запустил сам себя
+    running: Vec<PathBuf>,
+}
+
+impl Session {
+    pub fn new(graph: Graph<Label, f64>, start: Option<Vec<String>>) -> Self {
+        Session {
+            graph,
+            start,
+            done: Vec::new(),
+            undone: Vec::new(),
+            running: Vec::new(),
+        }
+    }
+
+    /// Выполняет команду. Команды, изменившие граф, попадают в историю.
+    pub fn execute(&mut self, cmd_parts: &[String]) -> Result<bool, String> {
+        match cmd_parts.first().map(String::as_str) {
+            Some("undo") => self.undo(),
+            Some("redo") => self.redo(),
+            Some("history") => self.history(cmd_parts),
+            Some("run") => self.run(cmd_parts),
+            Some(cmd) if !mutates_graph(cmd) => dispatch_cmd(cmd_parts, &mut self.graph),
+            _ => {
+                let before = self.graph.clone();
+                let result = dispatch_cmd(cmd_parts, &mut self.graph);
+
+                // Изменение определяется по содержимому графа, поэтому в историю
+                // попадают только команды, действительно изменившие его
+                if changed(&before, &self.graph) {
+                    self.done.push(Entry {
+                        command: cmd_parts.to_vec(),
+                        before: Some(before),
+                    });
+                    if let Some(stale) = self.done.len().checked_sub(UNDO_LIMIT + 1) {
+                        self.done[stale].before = None;
+                    }
+                    self.undone.clear();
+                }
+
+                result
+            }
+        }
+    }
+
+    fn undo(&mut self) -> Result<bool, String> {
+        let Some(entry) = self.done.last_mut() else {
+            return Err("Нечего отменять".to_string());
+        };
+        let Some(before) = entry.before.as_mut() else {
+            return Err(format!(
+                "Отменить можно только {UNDO_LIMIT} последних команд"
+            ));
+        };
+
+        std::mem::swap(&mut self.graph, before);
+        let entry = self.done.pop().unwrap();
+        println!("Отменена команда: {}", entry.command.join(" "));
+        self.undone.push(entry);
+        Ok(print_graph(&self.graph))
+    }
+
+    fn redo(&mut self) -> Result<bool, String> {
+        let Some(mut entry) = self.undone.pop() else {
+            return Err("Нечего повторять".to_string());
+        };
+
+        if let Some(after) = entry.before.as_mut() {
+            std::mem::swap(&mut self.graph, after);
+        }
+        println!("Повторена команда: {}", entry.command.join(" "));
+        self.done.push(entry);
+        Ok(print_graph(&self.graph))
+    }
+
+    fn history(&self, cmd_parts: &[String]) -> Result<bool, String> {
+        let Some(path) = cmd_parts.get(1) else {
+            if self.done.is_empty() {
+                println!("История пуста");
+            }
+            for (i, entry) in self.done.iter().enumerate() {
+                println!("{}. {}", i + 1, entry.command.join(" "));
+            }
+            return Ok(true);
+        };
+
+        let mut script = String::from("# Команды сессии, повторить: run <путь>\n");
+        match &self.start {
+            Some(start) => {
+                script.push_str(&start.join(" "));
+                script.push('\n');
+            }
+            None => script.push_str(
+                "# Начальный граф создан вручную: сценарий нужно выполнять на пустом\n\
+                 # графе того же вида\n",
+            ),
+        }
+        for entry in &self.done {
+            script.push_str(&entry.command.join(" "));
+            script.push('\n');
+        }
+        if let Err(e) = fs::write(path, script) {
+            return Err(format!("Ошибка при сохранении истории: {e}"));
+        }
+        println!("История сохранена в {}", path);
+        Ok(true)
+    }
+
+    // Выполняет команды из файла по одной строке; строки на `#` пропускаются.
+    // Сценарий, уже выполняемый выше по цепочке `run`, повторно не запускается.
+    fn run(&mut self, cmd_parts: &[String]) -> Result<bool, String> {
+        let Some(path) = cmd_parts.get(1) else {
+            return Err("Вы должны указать путь к сценарию".to_string());
+        };
+        let (script, canonical) = match fs::read_to_string(path).and_then(|script| {
+            let canonical = fs::canonicalize(path)?;
+            Ok((script, canonical))
+        }) {
+            Ok(read) => read,
+            Err(e) => return Err(format!("Ошибка при чтении сценария: {e}")),
+        };
+        if self.running.contains(&canonical) {
+            return Err(format!("Сценарий {path} уже выполняется"));
+        }
+
+        self.running.push(canonical);
+        let result = self.run_script(&script);
+        self.running.pop();
+        result
+    }
+
+    fn run_script(&mut self, script: &str) -> Result<bool, String> {
+        for (number, line) in script.lines().enumerate() {
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            let command: Vec<String> = line.split_whitespace().map(|s| s.to_string()).collect();
+            if command.is_empty() {
+                continue;
+            }
+
+            println!("> {}", command.join(" "));
+            match self.execute(&command) {
+                Ok(true) => continue,
+                Ok(false) => return Ok(false),
+                Err(e) => return Err(format!("Строка {}: {e}", number + 1)),
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+// Сравнивает графы вместе с весами, пропускными способностями и свойствами
+// рёбер, которые не участвуют в равенстве Edge
+fn changed(before: &Graph<Label, f64>, after: &Graph<Label, f64>) -> bool {
+    (before.directed, before.multigraph, before.mixed)
+        != (after.directed, after.multigraph, after.mixed)
+        || before.node_attributes != after.node_attributes
+        || before.nodes().ne(after.nodes())
+        || before.arcs().ne(after.arcs())
+        || before
+            .arcs()
+            .zip(after.arcs())
+            .any(|((_, _, first), (_, _, second))| {
+                first.rib != second.rib
+                    || first.weight.to_bits() != second.weight.to_bits()
+                    || first.capacity.to_bits() != second.capacity.to_bits()
+                    || first.attributes != second.attributes
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use sgu_graphs::files::save_graph;
+
+    use super::*;
+
+    fn command(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn nodes(session: &Session) -> Vec<String> {
+        session.graph.nodes().map(|node| node.to_string()).collect()
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut session = Session::new(Graph::new(true), None);
+        for line in ["add_node A", "add_node B", "nodes_count", "add_node A"] {
+            let _ = session.execute(&command(line));
+        }
+        // Неизвестная команда и повторная вершина граф не меняют
+        assert_eq!(session.done.len(), 2);
+
+        session.execute(&command("undo")).unwrap();
+        assert_eq!(nodes(&session), ["A"]);
+        session.execute(&command("undo")).unwrap();
+        assert!(nodes(&session).is_empty());
+        assert!(session.execute(&command("undo")).is_err());
+
+        session.execute(&command("redo")).unwrap();
+        session.execute(&command("redo")).unwrap();
+        assert_eq!(nodes(&session), ["A", "B"]);
+        assert!(session.execute(&command("redo")).is_err());
+    }
+
+    #[test]
+    fn test_new_command_discards_redo() {
+        let mut session = Session::new(Graph::new(true), None);
+        session.execute(&command("add_node A")).unwrap();
+        session.execute(&command("add_node B")).unwrap();
+        session.execute(&command("add_arc A B 2")).unwrap();
+        session.execute(&command("undo")).unwrap();
+
+        session.execute(&command("add_arc B A 3")).unwrap();
+        assert!(session.execute(&command("redo")).is_err());
+        assert!(session.graph.contains_edge(&"B".into(), &"A".into()));
+        assert!(!session.graph.contains_edge(&"A".into(), &"B".into()));
+
+        // Изменение одного веса тоже попадает в историю
+        session.execute(&command("set_weight B A 4")).unwrap();
+        assert_eq!(session.done.len(), 4);
+    }
+
+    #[test]
+    fn test_undo_limit() {
+        let mut session = Session::new(Graph::new(true), None);
+        for i in 0..=UNDO_LIMIT {
+            session.execute(&command(&format!("add_node {i}"))).unwrap();
+        }
+
+        for _ in 0..UNDO_LIMIT {
+            session.execute(&command("undo")).unwrap();
+        }
+        assert_eq!(nodes(&session), ["0"]);
+        assert!(session.execute(&command("undo")).is_err());
+        assert_eq!(session.done.len(), 1);
+    }
+
+    #[test]
+    fn test_history_replay() {
+        let path = std::env::temp_dir().join(format!("history-{}.txt", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+
+        let mut session = Session::new(Graph::new(true), None);
+        for line in ["add_node A", "add_node B", "add_arc A B 2", "add_node C"] {
+            session.execute(&command(line)).unwrap();
+        }
+        session.execute(&command("undo")).unwrap();
+        session
+            .execute(&command(&format!("history {path}")))
+            .unwrap();
+
+        let mut replayed = Session::new(Graph::new(true), None);
+        replayed.execute(&command(&format!("run {path}"))).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(!changed(&session.graph, &replayed.graph));
+        assert_eq!(replayed.done.len(), 3);
+    }
+
+    #[test]
+    fn test_history_starts_with_loaded_graph() {
+        let dir = std::env::temp_dir();
+        let source = dir.join(format!("start-{}.json", std::process::id()));
+        let path = dir.join(format!("loaded-{}.txt", std::process::id()));
+
+        let mut initial: Graph<Label, f64> = Graph::new(false);
+        initial.add_node("A".into());
+        initial.add_node("B".into());
+        save_graph(&initial, &source).unwrap();
+
+        let start = command(&format!("load {}", source.display()));
+        let mut session = Session::new(initial, Some(start));
+        session.execute(&command("add_rib A B 2")).unwrap();
+        session
+            .execute(&command(&format!("history {}", path.display())))
+            .unwrap();
+
+        let mut replayed = Session::new(Graph::new(true), None);
+        replayed
+            .execute(&command(&format!("run {}", path.display())))
+            .unwrap();
+        fs::remove_file(&source).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(!changed(&session.graph, &replayed.graph));
+    }
+
+    #[test]
+    fn test_non_finite_capacity_is_rejected() {
+        let mut session = Session::new(Graph::new(true), None);
+        for line in ["add_node A", "add_node B", "add_arc A B 1 2"] {
+            session.execute(&command(line)).unwrap();
+        }
+        for line in [
+            "set_capacity A B NaN",
+            "set_capacity A B inf",
+            "add_arc B A 1 -inf",
+        ] {
+            assert!(session.execute(&command(line)).is_err(), "{line}");
+        }
+        assert_eq!(session.done.len(), 3);
+    }
+
+    #[test]
+    fn test_export_dot_flow_requires_nodes() {
+        let path = std::env::temp_dir().join(format!("flow-{}.dot", std::process::id()));
+        let mut session = Session::new(Graph::new(true), None);
+        for line in ["add_node A", "add_node B", "add_arc A B 1 2"] {
+            session.execute(&command(line)).unwrap();
+        }
+
+        let error = session
+            .execute(&command(&format!("export_dot {} flow A C", path.display())))
+            .unwrap_err();
+        assert!(error.contains('C'), "{error}");
+        assert!(!path.exists());
+
+        session
+            .execute(&command(&format!("export_dot {} flow A B", path.display())))
+            .unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("color=red"));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_read_only_commands_are_not_recorded() {
+        let mut session = Session::new(Graph::new(true), None);
+        session.execute(&command("add_node A")).unwrap();
+        for line in ["print", "out_degree A", "min_dist", "scc_count"] {
+            assert!(!mutates_graph(&command(line)[0]));
+            session.execute(&command(line)).unwrap();
+        }
+        assert_eq!(session.done.len(), 1);
+    }
+
+    #[test]
+    fn test_recursive_script() {
+        let dir = std::env::temp_dir();
+        let first = dir.join(format!("first-{}.txt", std::process::id()));
+        let second = dir.join(format!("second-{}.txt", std::process::id()));
+        fs::write(&first, format!("add_node A\nrun {}\n", second.display())).unwrap();
+        fs::write(&second, format!("add_node B\nrun {}\n", first.display())).unwrap();
+
+        let mut session = Session::new(Graph::new(true), None);
+        let error = session
+            .execute(&command(&format!("run {}", first.display())))
+            .unwrap_err();
+        fs::remove_file(&first).unwrap();
+        fs::remove_file(&second).unwrap();
+
+        assert!(error.ends_with("уже выполняется"), "{error}");
+        assert_eq!(nodes(&session), ["A", "B"]);
+        assert!(session.running.is_empty());
+    }
+}
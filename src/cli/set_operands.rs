@@ -0,0 +1,51 @@
+use sgu_graphs::{files::load_graph, graph::Graph, label::Label, weight::WeightPolicy};
+
+use crate::cli::parse_policy::parse_policy;
+
+type Operands = (Graph<Label, f64>, Graph<Label, f64>);
+type WeightedOperands = (Graph<Label, f64>, Graph<Label, f64>, WeightPolicy);
+
+/// Операнды операции над множествами: `<путь>` — текущий граф и граф из
+/// файла, `<путь1> <путь2>` — два графа из файлов. Веса в результат не
+/// объединяются, поэтому политика весов считается ошибкой.
+pub fn set_operands(cmd_parts: &[String], graph: &Graph<Label, f64>) -> Result<Operands, String> {
+    let paths: Vec<&String> = cmd_parts[1..].iter().collect();
+    if let Some(policy) = paths.iter().find(|part| parse_policy(part).is_some()) {
+        return Err(format!(
+            "Операция не объединяет веса и не принимает политику {policy}"
+        ));
+    }
+    load_operands(&paths, graph)
+}
+
+/// Операнды операции, объединяющей веса совпавших рёбер: те же пути, что у
+/// [`set_operands`], и политика объединения весов в любом месте, по
+/// умолчанию `min`.
+pub fn weighted_operands(
+    cmd_parts: &[String],
+    graph: &Graph<Label, f64>,
+) -> Result<WeightedOperands, String> {
+    let mut policy = WeightPolicy::default();
+    let mut paths = Vec::new();
+    for part in &cmd_parts[1..] {
+        match parse_policy(part) {
+            Some(parsed) => policy = parsed,
+            None => paths.push(part),
+        }
+    }
+
+    let (first, second) = load_operands(&paths, graph)?;
+    Ok((first, second, policy))
+}
+
+fn load_operands(paths: &[&String], graph: &Graph<Label, f64>) -> Result<Operands, String> {
+    let load = |path: &String| {
+        load_graph(path).map_err(|e| format!("Ошибка при загрузке графа {path}: {e}"))
+    };
+    match paths {
+        [] => Err("Вы должны указать путь хотя бы к одному графу".to_string()),
+        [path] => Ok((graph.clone(), load(path)?)),
+        [first, second] => Ok((load(first)?, load(second)?)),
+        _ => Err("Операция принимает не больше двух графов".to_string()),
+    }
+}
@@ -0,0 +1,37 @@
+use sgu_graphs::{error::GraphError, graph::Graph, label::Label};
+
+use crate::cli::render_error::render_error;
+
+type WeightedGraph = Option<Graph<Label, f64>>;
+
+/// Убирает из команды опции `--weight <свойство>` и `--capacity <свойство>`
+/// и, если они были указаны, строит копию графа, в которой веса и
+/// пропускные способности взяты из свойств рёбер.
+pub fn weight_source(
+    cmd_parts: &[String],
+    graph: &Graph<Label, f64>,
+) -> Result<(Vec<String>, WeightedGraph), String> {
+    let mut parts = Vec::new();
+    let mut weighted: WeightedGraph = None;
+
+    let mut iter = cmd_parts.iter();
+    while let Some(part) = iter.next() {
+        if part != "--weight" && part != "--capacity" {
+            parts.push(part.clone());
+            continue;
+        }
+
+        let Some(key) = iter.next() else {
+            return Err(format!("После {part} нужно указать название свойства"));
+        };
+        let source = weighted.as_ref().unwrap_or(graph);
+        let result = if part == "--weight" {
+            source.weighted_by(key)
+        } else {
+            source.capacity_by(key)
+        };
+        weighted = Some(result.map_err(|e| render_error(GraphError::from(e)))?);
+    }
+
+    Ok((parts, weighted))
+}
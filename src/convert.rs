@@ -0,0 +1,273 @@
+use std::collections::{BTreeMap, BTreeSet, btree_map::Entry};
+
+use crate::{
+    graph::{Edge, Graph, Node},
+    weight::{Weight, WeightPolicy},
+};
+
+impl<N: Node, W: Weight> Graph<N, W> {
+    /// Граф с развёрнутыми дугами. Веса, пропускные способности, свойства
+    /// и изолированные вершины сохраняются, рёбра остаются рёбрами.
+    pub fn reversed(&self) -> Self {
+        if !self.directed {
+            return self.clone();
+        }
+
+        let mut edges = empty_lists(self);
+        for (from, to, edge) in self.arcs() {
+            let mut reversed = edge.clone();
+            reversed.node = from.clone();
+            edges.entry(to.clone()).or_default().insert(reversed);
+        }
+
+        with_edges(self, true, edges)
+    }
+
+    /// Неориентированный граф с теми же вершинами.
+    ///
+    /// В простом графе встречные дуги `u -> v` и `v -> u` сливаются в одно
+    /// ребро: веса и пропускные способности объединяются по `policy`, левой
+    /// считается дуга из меньшей вершины. В мультиграфе каждая дуга
+    /// становится отдельным ребром.
+    pub fn to_undirected(&self, policy: WeightPolicy) -> Self {
+        if !self.directed {
+            return self.clone();
+        }
+
+        let mut merged: BTreeMap<(N, N), Edge<N, W>> = BTreeMap::new();
+        let mut parallel: Vec<(N, Edge<N, W>)> = Vec::new();
+        // У ребра смешанного графа берётся одна половина
+        for (from, to, edge) in self.arcs().filter(|(from, to, e)| !e.rib || from <= to) {
+            let mut rib = edge.clone();
+            rib.rib = false;
+
+            if self.multigraph {
+                parallel.push((from.clone(), rib));
+                continue;
+            }
+
+            let key = if from <= to {
+                (from.clone(), to.clone())
+            } else {
+                (to.clone(), from.clone())
+            };
+            match merged.entry(key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(rib);
+                }
+                Entry::Occupied(mut entry) => merge_edge(entry.get_mut(), rib, policy),
+            }
+        }
+        parallel.extend(merged.into_iter().map(|((from, to), mut rib)| {
+            rib.node = to;
+            (from, rib)
+        }));
+
+        let mut edges = empty_lists(self);
+        for (from, rib) in parallel {
+            let mut half = rib.clone();
+            half.node = from.clone();
+            edges.entry(rib.node.clone()).or_default().insert(half);
+            edges.entry(from).or_default().insert(rib);
+        }
+
+        let mut graph = with_edges(self, false, edges);
+        graph.mixed = false;
+        graph
+    }
+
+    /// Ориентированный граф, в котором каждое ребро заменено парой встречных
+    /// дуг с теми же данными. В мультиграфе обратная дуга получает новый
+    /// идентификатор.
+    pub fn to_directed(&self) -> Self {
+        if self.directed && !self.mixed {
+            return self.clone();
+        }
+
+        let mut next_id = self.next_edge_id();
+        let mut edges = empty_lists(self);
+        for (from, to, edge) in self.arcs() {
+            let mut arc = edge.clone();
+            if self.is_rib(edge) {
+                arc.rib = false;
+                if self.multigraph && from > to {
+                    arc.id = next_id;
+                    next_id += 1;
+                }
+            }
+            edges.entry(from.clone()).or_default().insert(arc);
+        }
+
+        let mut graph = with_edges(self, true, edges);
+        graph.mixed = false;
+        graph
+    }
+}
+
+impl<N: Node, W: Weight> Graph<N, W> {
+    /// Граф с переименованными вершинами. Если `f` склеивает разные вершины,
+    /// их рёбра и свойства объединяются.
+    pub fn map_nodes<M: Node>(&self, f: impl Fn(&N) -> M) -> Graph<M, W> {
+        let edges = self
+            .edges
+            .iter()
+            .map(|(from, neighbours)| {
+                let neighbours = neighbours.iter().map(|edge| {
+                    let mut mapped = Edge::value(f(&edge.node))
+                        .weight(edge.weight)
+                        .capacity(edge.capacity)
+                        .id(edge.id)
+                        .rib(edge.rib)
+                        .build();
+                    mapped.attributes = edge.attributes.clone();
+                    mapped
+                });
+                (f(from), neighbours.collect::<Vec<_>>())
+            })
+            .fold(
+                BTreeMap::new(),
+                |mut acc: BTreeMap<M, BTreeSet<Edge<M, W>>>, (from, neighbours)| {
+                    acc.entry(from).or_default().extend(neighbours);
+                    acc
+                },
+            );
+
+        let mut graph = Graph::from_edges(self.directed, edges);
+        graph.multigraph = self.multigraph;
+        graph.mixed = self.mixed;
+        for (node, attributes) in &self.node_attributes {
+            graph
+                .node_attributes
+                .entry(f(node))
+                .or_default()
+                .extend(attributes.clone());
+        }
+        graph
+    }
+}
+
+fn empty_lists<N: Node, W: Weight>(graph: &Graph<N, W>) -> BTreeMap<N, BTreeSet<Edge<N, W>>> {
+    graph
+        .edges
+        .keys()
+        .map(|node| (node.clone(), BTreeSet::new()))
+        .collect()
+}
+
+// Новый граф с теми же флагами и свойствами вершин
+fn with_edges<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    directed: bool,
+    edges: BTreeMap<N, BTreeSet<Edge<N, W>>>,
+) -> Graph<N, W> {
+    let mut result = Graph::from_edges(directed, edges);
+    result.multigraph = graph.multigraph;
+    result.mixed = graph.mixed;
+    result.node_attributes = graph.node_attributes.clone();
+    result
+}
+
+/// Сливает данные `right` в `left`. Свойства берутся из левого ребра,
+/// а при политике [`WeightPolicy::Right`] — из правого.
+pub(crate) fn merge_edge<N, W: Weight>(
+    left: &mut Edge<N, W>,
+    right: Edge<N, W>,
+    policy: WeightPolicy,
+) {
+    left.weight = policy.merge(left.weight, right.weight);
+    left.capacity = policy.merge(left.capacity, right.capacity);
+    for (key, value) in right.attributes {
+        if policy == WeightPolicy::Right {
+            left.attributes.insert(key, value);
+        } else {
+            left.attributes.entry(key).or_insert(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::{btreemap, btreeset};
+
+    use super::*;
+
+    #[test]
+    fn test_transpose() {
+        let mut graph = Graph::new(true);
+
+        graph.add_node(0);
+        graph.add_node(1);
+        graph.add_node(2);
+
+        graph.add_edge(0, 1, 1, 1).unwrap();
+        graph.add_edge(0, 2, 1, 1).unwrap();
+        graph.add_edge(1, 2, 1, 1).unwrap();
+        graph.add_edge(2, 1, 1, 1).unwrap();
+
+        let graph = graph.reversed();
+
+        assert_eq!(
+            graph.edges,
+            btreemap! {
+                0 => btreeset! {},
+                1 => btreeset! {Edge::value(0).build(), Edge::value(2).build()},
+                2 => btreeset! {Edge::value(0).build(), Edge::value(1).build()}
+            }
+        )
+    }
+
+    #[test]
+    fn test_reversed_keeps_edge_data_and_isolated_nodes() {
+        let mut graph = Graph::new(true);
+
+        for i in 0..=3 {
+            graph.add_node(i);
+        }
+
+        graph.add_edge(0, 1, 5, 2).unwrap();
+        graph.add_edge(0, 2, 1, 1).unwrap();
+        graph.add_edge(1, 2, 1, 1).unwrap();
+        graph.add_edge(2, 1, 7, 3).unwrap();
+
+        let reversed = graph.reversed();
+
+        assert!(reversed.contains_node(&0) && reversed.contains_node(&3));
+        assert!(reversed.edges[&0].is_empty());
+        let back = reversed.edges[&1].iter().find(|e| e.node == 0).unwrap();
+        assert_eq!((back.weight, back.capacity), (5, 2));
+        let back = reversed.edges[&1].iter().find(|e| e.node == 2).unwrap();
+        assert_eq!((back.weight, back.capacity), (7, 3));
+        assert_eq!(reversed.in_degree(&0), 2);
+        assert_eq!(reversed.reversed().edges, graph.edges);
+    }
+
+    #[test]
+    fn test_to_undirected_merges_opposite_arcs() {
+        let mut graph = Graph::new(true);
+
+        for i in 1..=3 {
+            graph.add_node(i);
+        }
+
+        graph.add_edge(1, 2, 4, 1).unwrap();
+        graph.add_edge(2, 1, 6, 1).unwrap();
+        graph.add_edge(3, 2, 2, 1).unwrap();
+
+        let sum = graph.to_undirected(WeightPolicy::Sum);
+        assert!(!sum.directed);
+        assert_eq!(sum.edges[&1].iter().next().unwrap().weight, 10);
+        assert_eq!(sum.edges[&2].len(), 2);
+        assert!(sum.contains_edge(&2, &3));
+
+        let right = graph.to_undirected(WeightPolicy::Right);
+        assert_eq!(
+            right.edges[&2].iter().find(|e| e.node == 1).unwrap().weight,
+            6
+        );
+
+        let directed = sum.to_directed();
+        assert!(directed.directed);
+        assert_eq!(directed.edges[&3].iter().next().unwrap().weight, 2);
+        assert_eq!(directed.in_degree(&2), 2);
+    }
+}
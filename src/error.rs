@@ -0,0 +1,401 @@
+use std::fmt::{self, Debug, Display};
+
+use thiserror::Error;
+
+use crate::{
+    algorithms::{
+        acyclic::IsAcyclicError,
+        bellman_ford::MinDistanceError,
+        dijkstra::MinimalLengthToNodesError,
+        mst::MstPrimError,
+        neighbours::{IncomingNodesError, OutgoingNodesError},
+        outdegree::NodesWithGreaterOutdegreeError,
+        products::ProductError,
+        set_ops::SetOpError,
+    },
+    batch::{BatchError, OperationError},
+    graph::{
+        GraphAddEdgeError, GraphAddRibError, GraphAttributeError, GraphRemoveEdgeError,
+        GraphRemoveNodeError, GraphRemoveRibError,
+    },
+    validate::{Problem, ValidationError},
+};
+
+/// Какой из концов дуги или ребра не найден
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint {
+    /// Отдельная вершина, а не конец ребра
+    Node,
+    /// Начало дуги или первая вершина ребра
+    From,
+    /// Конец дуги или вторая вершина ребра
+    To,
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Endpoint::Node => "Node",
+            Endpoint::From => "From node",
+            Endpoint::To => "To node",
+        })
+    }
+}
+
+/// Единая ошибка операций над графом и алгоритмов.
+///
+/// Получается из ошибки любой операции через `?` или `From` и сохраняет
+/// вершины, к которым относилась операция.
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum GraphError<N: Debug, W: Debug = i32> {
+    #[error("{role} {node:?} does not exist")]
+    NodeNotFound { node: N, role: Endpoint },
+
+    #[error("Edge {from:?} -> {to:?}{} does not exist", edge_id(.id))]
+    EdgeNotFound { from: N, to: N, id: Option<usize> },
+
+    #[error("Edge {from:?} -> {to:?} already exists")]
+    EdgeAlreadyExists { from: N, to: N },
+
+    /// Операция требует ориентированного графа
+    #[error("Graph is undirected")]
+    UndirectedGraph,
+
+    /// Операция требует неориентированного графа
+    #[error("Graph must be undirected")]
+    DirectedGraph,
+
+    #[error("Mixed graphs are not supported")]
+    MixedGraph,
+
+    #[error("One graph is directed and the other is undirected")]
+    DirectednessMismatch,
+
+    #[error("Edge {from:?} -> {to:?} has negative weight {weight:?}")]
+    NegativeWeight { from: N, to: N, weight: W },
+
+    #[error("Capacity {from:?} -> {to:?} must be finite and not negative")]
+    NegativeCapacity { from: N, to: N },
+
+    #[error("Negative cycle detected")]
+    NegativeCycle,
+
+    #[error("Empty graph")]
+    EmptyGraph,
+
+    #[error("Edge has no attribute {0}")]
+    MissingAttribute(String),
+
+    #[error("Attribute {0} is not a valid weight")]
+    InvalidWeight(String),
+
+    #[error("Graph has {} structural problem(s){}", .0.len(), listed(.0))]
+    InvalidStructure(Vec<Problem<N, W>>),
+
+    /// Неудачные операции пакета вместе с их номерами
+    #[error(
+        "{} operation(s) failed{}",
+        .0.len(),
+        listed(.0.iter().map(|(index, error)| format!("#{index}: {error}")))
+    )]
+    Batch(Vec<(usize, GraphError<N, W>)>),
+}
+
+impl<N: Debug, W: Debug> GraphError<N, W> {
+    fn node_not_found(node: N, role: Endpoint) -> Self {
+        GraphError::NodeNotFound { node, role }
+    }
+}
+
+fn edge_id(id: &Option<usize>) -> String {
+    id.map(|id| format!(" (id {id})")).unwrap_or_default()
+}
+
+// Элементы списка, каждый с новой строки
+fn listed(items: impl IntoIterator<Item = impl Display>) -> String {
+    items
+        .into_iter()
+        .map(|item| format!("\n  {item}"))
+        .collect()
+}
+
+impl<N: Debug, W: Debug> From<GraphRemoveNodeError<N>> for GraphError<N, W> {
+    fn from(e: GraphRemoveNodeError<N>) -> Self {
+        match e {
+            GraphRemoveNodeError::NodeNotFound(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<GraphAddEdgeError<N>> for GraphError<N, W> {
+    fn from(e: GraphAddEdgeError<N>) -> Self {
+        match e {
+            GraphAddEdgeError::FromNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::From)
+            }
+            GraphAddEdgeError::ToNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::To)
+            }
+            GraphAddEdgeError::UndirectedGraph => GraphError::UndirectedGraph,
+            GraphAddEdgeError::EdgeAlreadyExists { from, to } => {
+                GraphError::EdgeAlreadyExists { from, to }
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<GraphRemoveEdgeError<N>> for GraphError<N, W> {
+    fn from(e: GraphRemoveEdgeError<N>) -> Self {
+        match e {
+            GraphRemoveEdgeError::FromNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::From)
+            }
+            GraphRemoveEdgeError::ToNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::To)
+            }
+            GraphRemoveEdgeError::UndirectedGraph => GraphError::UndirectedGraph,
+            GraphRemoveEdgeError::EdgeDoesNotExist { from, to, id } => {
+                GraphError::EdgeNotFound { from, to, id }
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<GraphAddRibError<N>> for GraphError<N, W> {
+    fn from(e: GraphAddRibError<N>) -> Self {
+        match e {
+            GraphAddRibError::FirstNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::From)
+            }
+            GraphAddRibError::SecondNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::To)
+            }
+            GraphAddRibError::RibAlreadyExists { first, second } => GraphError::EdgeAlreadyExists {
+                from: first,
+                to: second,
+            },
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<GraphRemoveRibError<N>> for GraphError<N, W> {
+    fn from(e: GraphRemoveRibError<N>) -> Self {
+        match e {
+            GraphRemoveRibError::FirstNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::From)
+            }
+            GraphRemoveRibError::SecondNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::To)
+            }
+            GraphRemoveRibError::RibDoesNotExist { first, second, id } => {
+                GraphError::EdgeNotFound {
+                    from: first,
+                    to: second,
+                    id,
+                }
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<GraphAttributeError<N>> for GraphError<N, W> {
+    fn from(e: GraphAttributeError<N>) -> Self {
+        match e {
+            GraphAttributeError::NodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+            GraphAttributeError::EdgeDoesNotExist { from, to } => {
+                GraphError::EdgeNotFound { from, to, id: None }
+            }
+            GraphAttributeError::MissingAttribute(key) => GraphError::MissingAttribute(key),
+            GraphAttributeError::InvalidWeight(key) => GraphError::InvalidWeight(key),
+            GraphAttributeError::NegativeCapacity { from, to } => {
+                GraphError::NegativeCapacity { from, to }
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<OutgoingNodesError<N>> for GraphError<N, W> {
+    fn from(e: OutgoingNodesError<N>) -> Self {
+        match e {
+            OutgoingNodesError::NodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+            OutgoingNodesError::UndirectedGraph => GraphError::UndirectedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<IncomingNodesError<N>> for GraphError<N, W> {
+    fn from(e: IncomingNodesError<N>) -> Self {
+        match e {
+            IncomingNodesError::NodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+            IncomingNodesError::UndirectedGraph => GraphError::UndirectedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<NodesWithGreaterOutdegreeError<N>> for GraphError<N, W> {
+    fn from(e: NodesWithGreaterOutdegreeError<N>) -> Self {
+        match e {
+            NodesWithGreaterOutdegreeError::NodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+            NodesWithGreaterOutdegreeError::UndirectedGraph => GraphError::UndirectedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<IsAcyclicError> for GraphError<N, W> {
+    fn from(e: IsAcyclicError) -> Self {
+        match e {
+            IsAcyclicError::UndirectedGraph => GraphError::UndirectedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<MstPrimError<N>> for GraphError<N, W> {
+    fn from(e: MstPrimError<N>) -> Self {
+        match e {
+            MstPrimError::StartNodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+            MstPrimError::DirectedGraph => GraphError::DirectedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<MinimalLengthToNodesError<N, W>> for GraphError<N, W> {
+    fn from(e: MinimalLengthToNodesError<N, W>) -> Self {
+        match e {
+            MinimalLengthToNodesError::NegativeWeight { from, to, weight } => {
+                GraphError::NegativeWeight { from, to, weight }
+            }
+            MinimalLengthToNodesError::NodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<MinDistanceError<N>> for GraphError<N, W> {
+    fn from(e: MinDistanceError<N>) -> Self {
+        match e {
+            MinDistanceError::NegativeCycle => GraphError::NegativeCycle,
+            MinDistanceError::EmptyGraph => GraphError::EmptyGraph,
+            MinDistanceError::NodeDoesNotExist(node) => {
+                GraphError::node_not_found(node, Endpoint::Node)
+            }
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<SetOpError> for GraphError<N, W> {
+    fn from(e: SetOpError) -> Self {
+        match e {
+            SetOpError::DirectednessMismatch => GraphError::DirectednessMismatch,
+            SetOpError::MixedGraph => GraphError::MixedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<ProductError> for GraphError<N, W> {
+    fn from(e: ProductError) -> Self {
+        match e {
+            ProductError::DirectednessMismatch => GraphError::DirectednessMismatch,
+            ProductError::MixedGraph => GraphError::MixedGraph,
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<ValidationError<N, W>> for GraphError<N, W> {
+    fn from(e: ValidationError<N, W>) -> Self {
+        GraphError::InvalidStructure(e.0)
+    }
+}
+
+impl<N: Debug, W: Debug> From<OperationError<N>> for GraphError<N, W> {
+    fn from(e: OperationError<N>) -> Self {
+        match e {
+            OperationError::RemoveNode(e) => e.into(),
+            OperationError::AddEdge(e) => e.into(),
+            OperationError::RemoveEdge(e) => e.into(),
+            OperationError::AddRib(e) => e.into(),
+            OperationError::RemoveRib(e) => e.into(),
+            OperationError::Attribute(e) => e.into(),
+        }
+    }
+}
+
+impl<N: Debug, W: Debug> From<BatchError<N, W>> for GraphError<N, W> {
+    fn from(e: BatchError<N, W>) -> Self {
+        GraphError::Batch(
+            e.0.into_iter()
+                .map(|failure| (failure.index, failure.error.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{batch::Operation, graph::Graph};
+
+    use super::*;
+
+    fn connect(graph: &mut Graph<char>, from: char, to: char) -> Result<usize, GraphError<char>> {
+        Ok(graph.add_edge(from, to, 1, 1)?)
+    }
+
+    #[test]
+    fn test_errors_carry_node_ids() {
+        let mut graph: Graph<char> = Graph::new_multigraph(true);
+        graph.add_node('A');
+
+        assert_eq!(
+            connect(&mut graph, 'A', 'B'),
+            Err(GraphError::NodeNotFound {
+                node: 'B',
+                role: Endpoint::To
+            })
+        );
+        assert_eq!(
+            connect(&mut graph, 'A', 'B').unwrap_err().to_string(),
+            "To node 'B' does not exist"
+        );
+
+        let batch = graph
+            .apply([Operation::RemoveEdge { from: 'A', to: 'A' }])
+            .map_err(GraphError::from);
+        assert_eq!(
+            batch,
+            Err(GraphError::Batch(vec![(
+                0,
+                GraphError::EdgeNotFound {
+                    from: 'A',
+                    to: 'A',
+                    id: None
+                }
+            )]))
+        );
+        assert_eq!(
+            batch.unwrap_err().to_string(),
+            "1 operation(s) failed\n  #0: Edge 'A' -> 'A' does not exist"
+        );
+
+        let id = connect(&mut graph, 'A', 'A').unwrap();
+        let missing = graph
+            .remove_edge_with_id('A', 'A', id + 1)
+            .map_err(GraphError::<char>::from)
+            .unwrap_err();
+        assert_eq!(
+            missing.to_string(),
+            format!("Edge 'A' -> 'A' (id {}) does not exist", id + 1)
+        );
+    }
+}
@@ -0,0 +1,779 @@
+// Экспорт и импорт формата DOT для Graphviz
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Display, Write},
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use serde_json::Value;
+use thiserror::Error;
+
+use super::{assemble, guess_value, value_text};
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Выделяемая часть графа, например результат алгоритма. Ребро выделяется,
+/// если в `arcs` есть пара его концов в любом порядке.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Highlight<N> {
+    pub nodes: BTreeSet<N>,
+    pub arcs: BTreeSet<(N, N)>,
+}
+
+impl<N> Default for Highlight<N> {
+    fn default() -> Self {
+        Highlight {
+            nodes: BTreeSet::new(),
+            arcs: BTreeSet::new(),
+        }
+    }
+}
+
+impl<N: Node> Highlight<N> {
+    /// Выделяет дуги и их концы
+    pub fn arcs(arcs: impl IntoIterator<Item = (N, N)>) -> Self {
+        let arcs: BTreeSet<(N, N)> = arcs.into_iter().collect();
+        let nodes = arcs
+            .iter()
+            .flat_map(|(from, to)| [from.clone(), to.clone()])
+            .collect();
+        Highlight { nodes, arcs }
+    }
+
+    /// Выделяет все рёбра и вершины другого графа, например остовного дерева
+    pub fn subgraph<W: Weight>(graph: &Graph<N, W>) -> Self {
+        let mut highlight =
+            Self::arcs(graph.arcs().map(|(from, to, _)| (from.clone(), to.clone())));
+        highlight.nodes.extend(graph.nodes().cloned());
+        highlight
+    }
+
+    fn contains(&self, from: &N, to: &N, rib: bool) -> bool {
+        self.arcs.contains(&(from.clone(), to.clone()))
+            || (rib && self.arcs.contains(&(to.clone(), from.clone())))
+    }
+}
+
+/// Описание графа на языке DOT. Дуги рисуются стрелками, рёбра — линиями
+/// (в смешанном графе — линиями без стрелок), подпись содержит вес и
+/// пропускную способность. Если у ребра есть своё свойство `label`, вес и
+/// пропускная способность записываются атрибутами `weight` и `capacity`.
+/// Свойства вершин и рёбер записываются списками атрибутов, выделенные
+/// вершины и рёбра рисуются красным.
+pub fn to_dot<N: Node + Display, W: Weight + Display>(
+    graph: &Graph<N, W>,
+    highlight: &Highlight<N>,
+) -> String {
+    let (keyword, connector) = if graph.directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    let mut dot = String::new();
+    writeln!(dot, "{keyword} {{").unwrap();
+    for node in graph.nodes() {
+        let mut attributes: Vec<String> = graph
+            .node_attributes
+            .get(node)
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| attribute(key, value))
+            .collect();
+        if highlight.nodes.contains(node) {
+            attributes.push("color=red, penwidth=2".to_string());
+        }
+        write!(dot, "    {}", quote(node)).unwrap();
+        if !attributes.is_empty() {
+            write!(dot, " [{}]", attributes.join(", ")).unwrap();
+        }
+        writeln!(dot, ";").unwrap();
+    }
+
+    for (from, to, edge) in graph.arcs() {
+        let rib = graph.is_rib(edge);
+        // Ребро записывается один раз, от меньшей вершины
+        if rib && from > to {
+            continue;
+        }
+
+        let mut attributes = match edge.attributes.get("label") {
+            Some(label) => vec![
+                format!("weight={}", quote(&edge.weight)),
+                format!("capacity={}", quote(&edge.capacity)),
+                attribute("label", label),
+            ],
+            None => vec![format!("label=\"{} / {}\"", edge.weight, edge.capacity)],
+        };
+        // Остальные имена заняты весом, пропускной способностью и видом ребра
+        attributes.extend(
+            edge.attributes
+                .iter()
+                .filter(|(key, _)| !["label", "weight", "capacity", "dir"].contains(&key.as_str()))
+                .map(|(key, value)| attribute(key, value)),
+        );
+        if graph.directed && rib {
+            attributes.push("dir=none".to_string());
+        }
+        if highlight.contains(from, to, rib) {
+            attributes.push("color=red, penwidth=2".to_string());
+        }
+        writeln!(
+            dot,
+            "    {} {connector} {} [{}];",
+            quote(from),
+            quote(to),
+            attributes.join(", ")
+        )
+        .unwrap();
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Сохраняет граф в файл DOT
+pub fn save_dot<N: Node + Display, W: Weight + Display>(
+    graph: &Graph<N, W>,
+    highlight: &Highlight<N>,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    fs::write(path, to_dot(graph, highlight))?;
+    Ok(())
+}
+
+// Атрибут `ключ="значение"`; ключ без кавычек, если это простой идентификатор
+fn attribute(key: &str, value: &Value) -> String {
+    let plain = key.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && key.starts_with(|c: char| !c.is_ascii_digit());
+    let key = if plain { key.to_string() } else { quote(&key) };
+    format!("{key}={}", quote(&value_text(value)))
+}
+
+fn quote(node: &impl Display) -> String {
+    format!(
+        "\"{}\"",
+        node.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum DotError {
+    #[error("Line {line}: unexpected {found}, expected {expected}")]
+    Unexpected {
+        line: usize,
+        found: String,
+        expected: &'static str,
+    },
+
+    #[error("Line {line}: {construct} are not supported")]
+    Unsupported {
+        line: usize,
+        construct: &'static str,
+    },
+
+    #[error("Line {line}: {connector} is not allowed in this kind of graph")]
+    WrongConnector {
+        line: usize,
+        connector: &'static str,
+    },
+
+    #[error("Line {line}: {id} is not a valid node id")]
+    InvalidNode { line: usize, id: String },
+
+    #[error("Line {line}: value {value} of attribute {key} is not a number")]
+    InvalidNumber {
+        line: usize,
+        key: String,
+        value: String,
+    },
+}
+
+/// Разбирает граф из текста на языке DOT.
+///
+/// `digraph` даёт ориентированный граф, `graph` — неориентированный; дуга
+/// `->` с `dir=none` становится ребром смешанного графа. Вес и пропускная
+/// способность берутся из атрибутов `weight` и `capacity`, а если их нет —
+/// из подписи вида `"вес / пропускная способность"`; остальные атрибуты
+/// становятся свойствами вершин и рёбер. Повторяющиеся рёбра делают граф
+/// мультиграфом, если он не объявлен как `strict`. Подграфы, порты и
+/// HTML-строки не поддерживаются.
+pub fn parse_dot<N: Node + FromStr, W: Weight + FromStr>(
+    text: &str,
+) -> Result<Graph<N, W>, DotError> {
+    let document = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+    }
+    .document()?;
+
+    build(document)
+}
+
+/// Загружает граф из файла DOT
+pub fn load_dot<N: Node + FromStr, W: Weight + FromStr>(
+    path: impl AsRef<Path>,
+) -> anyhow::Result<Graph<N, W>> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_dot(&text)?)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Kind {
+    Id(String),
+    Punct(&'static str),
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: Kind,
+    // Кавычки отличают строку "edge" от ключевого слова edge
+    quoted: bool,
+    line: usize,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, DotError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    let mut line_start = true;
+
+    while let Some(c) = chars.next() {
+        let starts_line = std::mem::replace(&mut line_start, c == '\n');
+        let punct = |p: &'static str| Token {
+            kind: Kind::Punct(p),
+            quoted: false,
+            line,
+        };
+
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => line_start = starts_line,
+            // Строки препроцессора C игнорируются, как и в Graphviz
+            '#' if starts_line => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        line_start = true;
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        line_start = true;
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            previous = c;
+                        }
+                        None => {
+                            return Err(DotError::Unexpected {
+                                line,
+                                found: "end of file".to_string(),
+                                expected: "end of comment",
+                            });
+                        }
+                    }
+                }
+            }
+            '{' => tokens.push(punct("{")),
+            '}' => tokens.push(punct("}")),
+            '[' => tokens.push(punct("[")),
+            ']' => tokens.push(punct("]")),
+            ';' => tokens.push(punct(";")),
+            ',' => tokens.push(punct(",")),
+            '=' => tokens.push(punct("=")),
+            ':' => tokens.push(punct(":")),
+            '-' if chars.peek() == Some(&'>') => {
+                chars.next();
+                tokens.push(punct("->"));
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                chars.next();
+                tokens.push(punct("--"));
+            }
+            '"' => {
+                let start = line;
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') if chars.peek() == Some(&'"') => {
+                            value.push(chars.next().unwrap())
+                        }
+                        Some('\\') if chars.peek() == Some(&'\n') => {
+                            chars.next();
+                            line += 1;
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            value.push(c);
+                        }
+                        None => {
+                            return Err(DotError::Unexpected {
+                                line: start,
+                                found: "end of file".to_string(),
+                                expected: "closing quote",
+                            });
+                        }
+                    }
+                }
+                tokens.push(Token {
+                    kind: Kind::Id(value),
+                    quoted: true,
+                    line: start,
+                });
+            }
+            '<' => {
+                return Err(DotError::Unsupported {
+                    line,
+                    construct: "HTML strings",
+                });
+            }
+            c if is_id_char(c) || c == '-' => {
+                let mut value = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !is_id_char(next) {
+                        break;
+                    }
+                    value.push(next);
+                    chars.next();
+                }
+                tokens.push(Token {
+                    kind: Kind::Id(value),
+                    quoted: false,
+                    line,
+                });
+            }
+            c => {
+                return Err(DotError::Unexpected {
+                    line,
+                    found: format!("character {c:?}"),
+                    expected: "node id or punctuation",
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+type AttrList = Vec<(String, String)>;
+
+struct Statement {
+    line: usize,
+    from: String,
+    // Для описания вершины конца нет
+    to: Option<String>,
+    attributes: AttrList,
+}
+
+struct Document {
+    strict: bool,
+    directed: bool,
+    statements: Vec<Statement>,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn document(mut self) -> Result<Document, DotError> {
+        let strict = self.keyword("strict");
+        let directed = if self.keyword("digraph") {
+            true
+        } else if self.keyword("graph") {
+            false
+        } else {
+            return Err(self.unexpected("graph or digraph"));
+        };
+
+        if matches!(self.peek(), Some(Kind::Id(_))) {
+            self.pos += 1;
+        }
+        self.expect("{")?;
+
+        let mut statements = Vec::new();
+        let mut edge_defaults = AttrList::new();
+        loop {
+            let line = self.line();
+            if self.punct("}") {
+                break;
+            }
+            if self.punct(";") {
+                continue;
+            }
+            if self.keyword("subgraph") || self.peek() == Some(&Kind::Punct("{")) {
+                return Err(DotError::Unsupported {
+                    line,
+                    construct: "subgraphs",
+                });
+            }
+
+            // Атрибуты по умолчанию: для рёбер запоминаются, `graph` и
+            // `node` влияют только на рисование
+            if self.keyword("edge") {
+                edge_defaults.extend(self.attributes()?);
+                continue;
+            }
+            if self.keyword("graph") || self.keyword("node") {
+                self.attributes()?;
+                continue;
+            }
+
+            let from = self.id()?;
+            if self.punct("=") {
+                self.id()?;
+                continue;
+            }
+
+            let mut chain = vec![from];
+            while let Some(connector) = self.connector() {
+                if connector != if directed { "->" } else { "--" } {
+                    return Err(DotError::WrongConnector { line, connector });
+                }
+                if self.peek() == Some(&Kind::Punct("{")) || self.keyword("subgraph") {
+                    return Err(DotError::Unsupported {
+                        line,
+                        construct: "subgraphs",
+                    });
+                }
+                chain.push(self.id()?);
+            }
+            let attributes = self.attributes()?;
+
+            if chain.len() == 1 {
+                statements.push(Statement {
+                    line,
+                    from: chain.pop().unwrap(),
+                    to: None,
+                    attributes,
+                });
+                continue;
+            }
+
+            let mut merged = edge_defaults.clone();
+            merged.extend(attributes);
+            for pair in chain.windows(2) {
+                statements.push(Statement {
+                    line,
+                    from: pair[0].clone(),
+                    to: Some(pair[1].clone()),
+                    attributes: merged.clone(),
+                });
+            }
+        }
+
+        if self.pos < self.tokens.len() {
+            return Err(self.unexpected("end of file"));
+        }
+
+        Ok(Document {
+            strict,
+            directed,
+            statements,
+        })
+    }
+
+    // Ноль или несколько списков `[ключ = значение, ...]`
+    fn attributes(&mut self) -> Result<AttrList, DotError> {
+        let mut attributes = AttrList::new();
+        while self.punct("[") {
+            while !self.punct("]") {
+                let key = self.id()?;
+                self.expect("=")?;
+                let value = self.id()?;
+                attributes.push((key, value));
+                if !self.punct(",") {
+                    self.punct(";");
+                }
+            }
+        }
+        Ok(attributes)
+    }
+
+    fn id(&mut self) -> Result<String, DotError> {
+        let Some(Kind::Id(id)) = self.peek().cloned() else {
+            return Err(self.unexpected("node id"));
+        };
+        self.pos += 1;
+
+        if self.peek() == Some(&Kind::Punct(":")) {
+            return Err(DotError::Unsupported {
+                line: self.line(),
+                construct: "ports",
+            });
+        }
+        Ok(id)
+    }
+
+    fn connector(&mut self) -> Option<&'static str> {
+        let connector = match self.peek()? {
+            Kind::Punct(p @ ("->" | "--")) => *p,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(connector)
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let Some(token) = self.tokens.get(self.pos) else {
+            return false;
+        };
+        let matched = !token.quoted
+            && matches!(&token.kind, Kind::Id(id) if id.eq_ignore_ascii_case(keyword));
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn punct(&mut self, punct: &str) -> bool {
+        let matched = matches!(self.peek(), Some(Kind::Punct(p)) if *p == punct);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn expect(&mut self, punct: &'static str) -> Result<(), DotError> {
+        if self.punct(punct) {
+            Ok(())
+        } else {
+            Err(self.unexpected(punct))
+        }
+    }
+
+    fn peek(&self) -> Option<&Kind> {
+        self.tokens.get(self.pos).map(|token| &token.kind)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |token| token.line)
+    }
+
+    fn unexpected(&self, expected: &'static str) -> DotError {
+        let found = match self.peek() {
+            Some(Kind::Id(id)) => format!("\"{id}\""),
+            Some(Kind::Punct(p)) => p.to_string(),
+            None => "end of file".to_string(),
+        };
+        DotError::Unexpected {
+            line: self.line(),
+            found,
+            expected,
+        }
+    }
+}
+
+fn build<N: Node + FromStr, W: Weight + FromStr>(
+    document: Document,
+) -> Result<Graph<N, W>, DotError> {
+    let directed = document.directed;
+    let mut node_attributes: BTreeMap<N, Attributes> = BTreeMap::new();
+    let mut links = Vec::new();
+
+    for statement in document.statements {
+        let line = statement.line;
+        let node = |id: &String| {
+            id.parse::<N>().map_err(|_| DotError::InvalidNode {
+                line,
+                id: id.clone(),
+            })
+        };
+
+        let from = node(&statement.from)?;
+        node_attributes.entry(from.clone()).or_default();
+        let Some(to) = &statement.to else {
+            let attributes = node_attributes.entry(from).or_default();
+            for (key, value) in statement.attributes {
+                attributes.insert(key, guess_value(value));
+            }
+            continue;
+        };
+        let to = node(to)?;
+        node_attributes.entry(to.clone()).or_default();
+
+        let mut weight = None;
+        let mut capacity = None;
+        let mut label = None;
+        let mut rib = !directed;
+        let mut attributes = Attributes::new();
+        for (key, value) in statement.attributes {
+            let number = |value: &String| {
+                value
+                    .trim()
+                    .parse::<W>()
+                    .map_err(|_| DotError::InvalidNumber {
+                        line,
+                        key: key.clone(),
+                        value: value.clone(),
+                    })
+            };
+            match key.as_str() {
+                "weight" => weight = Some(number(&value)?),
+                "capacity" => capacity = Some(number(&value)?),
+                "label" => label = Some(value),
+                "dir" if directed => rib = value == "none",
+                _ => {
+                    attributes.insert(key, guess_value(value));
+                }
+            }
+        }
+
+        // Подпись вида "вес / пропускная способность", как при экспорте. При
+        // явных весе или пропускной способности подпись — обычное свойство
+        if let Some(label) = label {
+            let explicit = weight.is_some() || capacity.is_some();
+            let mut parts = label.split('/').map(|part| part.trim().parse::<W>());
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(Ok(w)), second, None) if !explicit && !matches!(second, Some(Err(_))) => {
+                    weight = Some(w);
+                    capacity = second.and_then(Result::ok);
+                }
+                _ => {
+                    attributes.insert("label".to_string(), Value::String(label));
+                }
+            }
+        }
+
+        let mut edge = Edge::value(to)
+            .weight(weight.unwrap_or(W::one()))
+            .capacity(capacity.unwrap_or(W::one()))
+            .build();
+        edge.attributes = attributes;
+        links.push((from, edge, rib));
+    }
+
+    Ok(assemble(directed, node_attributes, links, document.strict))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_graph_with_highlight() {
+        let mut graph: Graph<char> = Graph::new_mixed();
+        for node in 'A'..='C' {
+            graph.add_node(node);
+        }
+        graph.add_rib('A', 'B', 2, 1).unwrap();
+        graph.add_edge('B', 'C', 3, 5).unwrap();
+
+        let dot = to_dot(&graph, &Highlight::arcs([('B', 'A')]));
+        assert_eq!(
+            dot,
+            "digraph {\n    \"A\" [color=red, penwidth=2];\n    \"B\" [color=red, penwidth=2];\n    \"C\";\n    \"A\" -> \"B\" [label=\"2 / 1\", dir=none, color=red, penwidth=2];\n    \"B\" -> \"C\" [label=\"3 / 5\"];\n}\n"
+        );
+
+        let undirected = graph.to_undirected(Default::default());
+        assert!(
+            to_dot(&undirected, &Highlight::default())
+                .contains("\"B\" -- \"C\" [label=\"3 / 5\"];")
+        );
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let mut graph: Graph<char> = Graph::new_mixed();
+        for node in 'A'..='D' {
+            graph.add_node(node);
+        }
+        graph.add_rib('A', 'B', 2, 1).unwrap();
+        graph.add_edge('B', 'C', 3, 5).unwrap();
+        graph.add_edge('C', 'D', 4, 1).unwrap();
+        graph
+            .node_attributes
+            .entry('A')
+            .or_default()
+            .insert("full name".into(), "Вершина \"A\"".into());
+        graph
+            .node_attributes
+            .entry('D')
+            .or_default()
+            .insert("size".into(), 3.into());
+        graph
+            .set_edge_attribute('B', 'C', "color", "blue".into())
+            .unwrap();
+        graph
+            .set_edge_attribute('C', 'D', "label", "объезд".into())
+            .unwrap();
+        graph
+            .set_edge_attribute('C', 'D', "visited", true.into())
+            .unwrap();
+
+        let dot = to_dot(&graph, &Highlight::default());
+        assert!(dot.contains(
+            "\"C\" -> \"D\" [weight=\"4\", capacity=\"1\", label=\"объезд\", visited=\"true\"];"
+        ));
+        let parsed: Graph<char> = parse_dot(&dot).unwrap();
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            serde_json::to_string(&graph).unwrap()
+        );
+
+        let text = "strict graph G {\n  // комментарий\n  edge [capacity=4]\n  1 -- 2 -- 3 [weight=7, color=blue]\n  1 [shape=box]\n}";
+        let parsed: Graph<usize> = parse_dot(text).unwrap();
+        assert!(!parsed.directed && !parsed.multigraph);
+        let edge = parsed.out_edges(&3).next().unwrap();
+        assert_eq!((edge.node, edge.weight, edge.capacity), (2, 7, 4));
+        assert_eq!(edge.attributes["color"], "blue");
+        assert_eq!(parsed.node_attributes[&1]["shape"], "box");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |text: &str| parse_dot::<usize, i32>(text).unwrap_err();
+
+        assert!(matches!(
+            parse("digraph {\n 1 -> 2\n subgraph { 3 } }"),
+            DotError::Unsupported { line: 3, .. }
+        ));
+        assert!(matches!(
+            parse("graph { 1 -> 2 }"),
+            DotError::WrongConnector {
+                connector: "->",
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("digraph { 1 -> 2 [weight=x] }"),
+            DotError::InvalidNumber { .. }
+        ));
+        assert!(matches!(
+            parse("digraph { 1 -> 2"),
+            DotError::Unexpected { .. }
+        ));
+    }
+}
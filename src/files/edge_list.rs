@@ -0,0 +1,388 @@
+// Список рёбер: CSV или текстовый файл со строками `from to weight capacity`
+
+use std::{collections::BTreeMap, fmt::Write, fs, path::Path, str::FromStr};
+
+use bon::Builder;
+use serde::Serialize;
+use thiserror::Error;
+
+use super::{Link, assemble, guess_value, serialized, value_text};
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Настройки чтения и записи списка рёбер
+#[derive(Clone, Debug, Builder)]
+pub struct EdgeList {
+    /// Ориентированы ли рёбра при чтении. При записи берётся из графа.
+    pub directed: bool,
+
+    /// Разделитель столбцов. Без него при чтении выбирается табуляция,
+    /// `;` или `,`, если они есть в первой строке, иначе пробелы; при
+    /// записи — запятая.
+    pub delimiter: Option<char>,
+
+    /// Есть ли строка заголовка. Без значения при чтении заголовок
+    /// распознаётся по названиям столбцов, при записи — пишется.
+    pub header: Option<bool>,
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum CsvError {
+    #[error("Line {line}: missing column {column}")]
+    MissingColumn { line: usize, column: &'static str },
+
+    #[error("Line {line}: more cells than columns")]
+    TooManyColumns { line: usize },
+
+    #[error("Line {line}: unclosed quote")]
+    UnclosedQuote { line: usize },
+
+    #[error("Line {line}: {id} is not a valid node id")]
+    InvalidNode { line: usize, id: String },
+
+    #[error("Line {line}: value {value} in column {column} is not a number")]
+    InvalidNumber {
+        line: usize,
+        column: String,
+        value: String,
+    },
+}
+
+// Роль столбца при чтении
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Column {
+    From,
+    To,
+    Weight,
+    Capacity,
+    Attribute(String),
+}
+
+impl Column {
+    fn named(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "from" | "source" => Column::From,
+            "to" | "target" => Column::To,
+            "weight" => Column::Weight,
+            "capacity" => Column::Capacity,
+            _ => Column::Attribute(name.trim().to_string()),
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Column::From => "from",
+            Column::To => "to",
+            Column::Weight => "weight",
+            Column::Capacity => "capacity",
+            Column::Attribute(name) => name,
+        }
+    }
+}
+
+/// Разбирает список рёбер. Строка из одной вершины добавляет вершину без
+/// рёбер, пустые строки и строки, начинающиеся с `#`, пропускаются. Без
+/// заголовка столбцы идут в порядке `from, to, weight, capacity`, последние
+/// два необязательны. С заголовком порядок столбцов любой, а столбцы с
+/// неизвестными названиями становятся свойствами рёбер. Повторяющиеся
+/// рёбра делают граф мультиграфом.
+pub fn parse_edge_list<N: Node + FromStr, W: Weight + FromStr>(
+    text: &str,
+    options: &EdgeList,
+) -> Result<Graph<N, W>, CsvError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .peekable();
+
+    let delimiter = options.delimiter.or_else(|| {
+        let (_, first) = lines.peek()?;
+        ['\t', ';', ','].into_iter().find(|&c| first.contains(c))
+    });
+
+    let mut columns = vec![Column::From, Column::To, Column::Weight, Column::Capacity];
+    if let Some(&(line, first)) = lines.peek() {
+        let cells = split_row(first, delimiter, line)?;
+        let header = options.header.unwrap_or_else(|| {
+            cells
+                .iter()
+                .any(|cell| !matches!(Column::named(cell), Column::Attribute(_)))
+        });
+        if header {
+            columns = cells.iter().map(|cell| Column::named(cell)).collect();
+            for (column, name) in [(Column::From, "from"), (Column::To, "to")] {
+                if !columns.contains(&column) {
+                    return Err(CsvError::MissingColumn { line, column: name });
+                }
+            }
+            lines.next();
+        }
+    }
+
+    let mut nodes: BTreeMap<N, Attributes> = BTreeMap::new();
+    let mut links: Vec<Link<N, W>> = Vec::new();
+    for (line, row) in lines {
+        let cells = split_row(row, delimiter, line)?;
+        if cells.len() > columns.len() {
+            return Err(CsvError::TooManyColumns { line });
+        }
+
+        let mut from = None;
+        let mut to = None;
+        let mut edge_weight = W::one();
+        let mut edge_capacity = W::one();
+        let mut attributes = Attributes::new();
+        for (column, cell) in columns.iter().zip(cells) {
+            let number = || {
+                cell.trim()
+                    .parse::<W>()
+                    .map_err(|_| CsvError::InvalidNumber {
+                        line,
+                        column: column.name().to_string(),
+                        value: cell.clone(),
+                    })
+            };
+            match column {
+                Column::From => from = Some(cell),
+                Column::To => to = Some(cell).filter(|cell| !cell.is_empty()),
+                _ if cell.trim().is_empty() => {}
+                Column::Weight => edge_weight = number()?,
+                Column::Capacity => edge_capacity = number()?,
+                Column::Attribute(name) => {
+                    attributes.insert(name.clone(), guess_value(cell));
+                }
+            }
+        }
+
+        let node = |id: String| {
+            id.parse::<N>()
+                .map_err(|_| CsvError::InvalidNode { line, id })
+        };
+        let from = node(from.ok_or(CsvError::MissingColumn {
+            line,
+            column: "from",
+        })?)?;
+        nodes.entry(from.clone()).or_default();
+        let Some(to) = to else {
+            continue;
+        };
+        let to = node(to)?;
+        nodes.entry(to.clone()).or_default();
+
+        let mut edge = Edge::value(to)
+            .weight(edge_weight)
+            .capacity(edge_capacity)
+            .build();
+        edge.attributes = attributes;
+        links.push((from, edge, !options.directed));
+    }
+
+    Ok(assemble(options.directed, nodes, links, false))
+}
+
+// Ячейки строки; значения в двойных кавычках могут содержать разделитель,
+// а кавычка внутри них удваивается. Без разделителя ячейки разделяются
+// любым числом пробельных символов, но пустая ячейка `""` сохраняется.
+fn split_row(row: &str, delimiter: Option<char>, line: usize) -> Result<Vec<String>, CsvError> {
+    let separates = |c: char| match delimiter {
+        Some(delimiter) => c == delimiter,
+        None => c.is_whitespace(),
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    // Начата ли ячейка: при разделении пробелами пустые ячейки бывают только
+    // в кавычках
+    let mut started = false;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if cell.trim().is_empty() => {
+                quoted = true;
+                started = true;
+                cell.clear();
+            }
+            c if separates(c) && !quoted => {
+                if started || delimiter.is_some() {
+                    cells.push(std::mem::take(&mut cell).trim().to_string());
+                }
+                started = false;
+            }
+            c => {
+                cell.push(c);
+                started = true;
+            }
+        }
+    }
+    if quoted {
+        return Err(CsvError::UnclosedQuote { line });
+    }
+    if started || delimiter.is_some() {
+        cells.push(cell.trim().to_string());
+    }
+    Ok(cells)
+}
+
+/// Список рёбер с заголовком `from,to,weight,capacity` и столбцами для
+/// свойств рёбер. Ребро неориентированного графа записывается одной
+/// строкой, ребро смешанного графа — двумя противоположными дугами.
+/// Вершины без рёбер записываются строками из одной ячейки.
+pub fn to_edge_list<N: Node + Serialize, W: Weight + Serialize>(
+    graph: &Graph<N, W>,
+    options: &EdgeList,
+) -> String {
+    let delimiter = options.delimiter.unwrap_or(',');
+    let links: Vec<_> = graph
+        .arcs()
+        .filter(|(from, to, _)| graph.directed || from <= to)
+        .collect();
+    let mut names: Vec<&String> = links
+        .iter()
+        .flat_map(|(_, _, edge)| edge.attributes.keys())
+        .collect();
+    names.sort();
+    names.dedup();
+
+    let cell = |text: String| {
+        if text.contains([delimiter, '"', '\n']) || (delimiter == ' ' && text.is_empty()) {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text
+        }
+    };
+
+    let mut list = String::new();
+    let mut write_row = |cells: Vec<String>| {
+        let row: Vec<String> = cells.into_iter().map(cell).collect();
+        writeln!(list, "{}", row.join(&delimiter.to_string())).unwrap();
+    };
+    if options.header.unwrap_or(true) {
+        let mut header: Vec<String> = ["from", "to", "weight", "capacity"]
+            .map(String::from)
+            .to_vec();
+        header.extend(names.iter().map(|name| name.to_string()));
+        write_row(header);
+    }
+
+    for node in graph.nodes() {
+        if graph.degree(node) == 0 {
+            write_row(vec![serialized(node)]);
+        }
+    }
+    for (from, to, edge) in links {
+        let mut row = vec![
+            serialized(from),
+            serialized(to),
+            serialized(&edge.weight),
+            serialized(&edge.capacity),
+        ];
+        row.extend(names.iter().map(|name| {
+            edge.attributes
+                .get(*name)
+                .map(value_text)
+                .unwrap_or_default()
+        }));
+        write_row(row);
+    }
+    list
+}
+
+/// Загружает граф из списка рёбер
+pub fn load_edge_list<N: Node + FromStr, W: Weight + FromStr>(
+    path: impl AsRef<Path>,
+    options: &EdgeList,
+) -> anyhow::Result<Graph<N, W>> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_edge_list(&text, options)?)
+}
+
+/// Сохраняет граф списком рёбер
+pub fn save_edge_list<N: Node + Serialize, W: Weight + Serialize>(
+    graph: &Graph<N, W>,
+    path: impl AsRef<Path>,
+    options: &EdgeList,
+) -> anyhow::Result<()> {
+    fs::write(path, to_edge_list(graph, options))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_without_header() {
+        let options = EdgeList::builder().directed(false).build();
+        let text = "# комментарий\n1 2 5\n2 3 1.5 4\n\n4\n2 1\n";
+        let graph: Graph<usize, f64> = parse_edge_list(text, &options).unwrap();
+
+        assert!(!graph.directed && graph.multigraph);
+        assert_eq!(graph.nodes().count(), 4);
+        assert_eq!(graph.degree(&4), 0);
+        let edge = graph.out_edges(&3).next().unwrap();
+        assert_eq!((edge.weight, edge.capacity), (1.5, 4.0));
+
+        let text = "1;2;x\n";
+        let options = EdgeList::builder().directed(true).build();
+        assert!(matches!(
+            parse_edge_list::<usize, f64>(text, &options),
+            Err(CsvError::InvalidNumber { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_with_attributes() {
+        let text = "target;source;road\nB;A;\"М-5; трасса\"\nC;B;\n";
+        let options = EdgeList::builder().directed(true).build();
+        let graph: Graph<String> = parse_edge_list(text, &options).unwrap();
+        assert_eq!(
+            graph.out_edges(&"A".to_string()).next().unwrap().attributes["road"],
+            "М-5; трасса"
+        );
+
+        let saved = to_edge_list(
+            &graph,
+            &EdgeList::builder().directed(true).delimiter(';').build(),
+        );
+        assert_eq!(
+            saved,
+            "from;to;weight;capacity;road\nA;B;1;1;\"М-5; трасса\"\nB;C;1;1;\n"
+        );
+        let parsed: Graph<String> = parse_edge_list(&saved, &options).unwrap();
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            serde_json::to_string(&graph).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_space_delimited_round_trip() {
+        let text = "from;to;road\nА;Б;М 5\nБ;В;\nВ;\"Г Д\";\"\"\"тропа\"\"\"\n";
+        let options = EdgeList::builder().directed(true).build();
+        let graph: Graph<String> = parse_edge_list(text, &options).unwrap();
+
+        let saved = to_edge_list(
+            &graph,
+            &EdgeList::builder().directed(true).delimiter(' ').build(),
+        );
+        assert_eq!(
+            saved,
+            "from to weight capacity road\nА Б 1 1 \"М 5\"\nБ В 1 1 \"\"\nВ \"Г Д\" 1 1 \"\"\"тропа\"\"\"\n"
+        );
+        let parsed: Graph<String> = parse_edge_list(&saved, &options).unwrap();
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            serde_json::to_string(&graph).unwrap()
+        );
+    }
+}
@@ -0,0 +1,325 @@
+// Импорт и экспорт формата GEXF (Gephi)
+
+use std::{collections::BTreeMap, fmt::Write, fs, path::Path, str::FromStr};
+
+use roxmltree::{Document, Node as XmlNode};
+use serde::Serialize;
+
+use super::{
+    Link, assemble, serialized, value_text,
+    xml::{
+        AttrType, Declarations, Declared, XmlError, children, declare, escape, line, parse_node,
+        parse_number, parse_value, required,
+    },
+};
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Описание графа в GEXF 1.3. Вес записывается атрибутом `weight` ребра,
+/// пропускная способность — свойством `capacity`, рёбра смешанного графа
+/// получают `type="undirected"`.
+pub fn to_gexf<N: Node + Serialize, W: Weight + Serialize>(graph: &Graph<N, W>) -> String {
+    let links: Vec<_> = graph
+        .arcs()
+        .filter(|(from, to, edge)| !graph.is_rib(edge) || from <= to)
+        .collect();
+    let capacities: Vec<_> = links
+        .iter()
+        .map(|(_, _, edge)| serde_json::to_value(edge.capacity).unwrap_or_default())
+        .collect();
+    let capacity_type = if capacities.is_empty() {
+        AttrType::Double
+    } else {
+        AttrType::of(&capacities)
+    };
+
+    let node_keys = declare(graph.node_attributes.values().flatten());
+    let edge_keys: Vec<_> = declare(links.iter().flat_map(|(_, _, edge)| &edge.attributes))
+        .into_iter()
+        .filter(|(name, _)| name != "capacity")
+        .collect();
+
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(xml, r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#).unwrap();
+    let edge_type = if graph.directed {
+        "directed"
+    } else {
+        "undirected"
+    };
+    writeln!(
+        xml,
+        r#"  <graph defaultedgetype="{edge_type}" mode="static">"#
+    )
+    .unwrap();
+
+    writeln!(xml, r#"    <attributes class="node">"#).unwrap();
+    write_declarations(&mut xml, &node_keys);
+    writeln!(xml, "    </attributes>").unwrap();
+    writeln!(xml, r#"    <attributes class="edge">"#).unwrap();
+    writeln!(
+        xml,
+        r#"      <attribute id="capacity" title="capacity" type="{}"/>"#,
+        capacity_type.name()
+    )
+    .unwrap();
+    write_declarations(&mut xml, &edge_keys);
+    writeln!(xml, "    </attributes>").unwrap();
+
+    writeln!(xml, "    <nodes>").unwrap();
+    for node in graph.nodes() {
+        let id = escape(&serialized(node));
+        match graph.node_attributes.get(node) {
+            Some(attributes) if !attributes.is_empty() => {
+                writeln!(xml, r#"      <node id="{id}" label="{id}">"#).unwrap();
+                writeln!(xml, "        <attvalues>").unwrap();
+                write_values(&mut xml, &node_keys, attributes);
+                writeln!(xml, "        </attvalues>").unwrap();
+                writeln!(xml, "      </node>").unwrap();
+            }
+            _ => writeln!(xml, r#"      <node id="{id}" label="{id}"/>"#).unwrap(),
+        }
+    }
+    writeln!(xml, "    </nodes>").unwrap();
+
+    writeln!(xml, "    <edges>").unwrap();
+    for (index, (from, to, edge)) in links.into_iter().enumerate() {
+        write!(
+            xml,
+            r#"      <edge id="{index}" source="{}" target="{}" weight="{}""#,
+            escape(&serialized(from)),
+            escape(&serialized(to)),
+            escape(&serialized(&edge.weight))
+        )
+        .unwrap();
+        if graph.directed && edge.rib {
+            write!(xml, r#" type="undirected""#).unwrap();
+        }
+        writeln!(xml, ">").unwrap();
+        writeln!(xml, "        <attvalues>").unwrap();
+        writeln!(
+            xml,
+            r#"          <attvalue for="capacity" value="{}"/>"#,
+            escape(&serialized(&edge.capacity))
+        )
+        .unwrap();
+        write_values(&mut xml, &edge_keys, &edge.attributes);
+        writeln!(xml, "        </attvalues>").unwrap();
+        writeln!(xml, "      </edge>").unwrap();
+    }
+    writeln!(xml, "    </edges>").unwrap();
+    writeln!(xml, "  </graph>").unwrap();
+    writeln!(xml, "</gexf>").unwrap();
+    xml
+}
+
+fn write_declarations(xml: &mut String, keys: &[(String, AttrType)]) {
+    for (index, (name, kind)) in keys.iter().enumerate() {
+        writeln!(
+            xml,
+            r#"      <attribute id="{index}" title="{}" type="{}"/>"#,
+            escape(name),
+            kind.name()
+        )
+        .unwrap();
+    }
+}
+
+fn write_values(xml: &mut String, keys: &[(String, AttrType)], attributes: &Attributes) {
+    for (index, (name, _)) in keys.iter().enumerate() {
+        if let Some(value) = attributes.get(name) {
+            writeln!(
+                xml,
+                r#"          <attvalue for="{index}" value="{}"/>"#,
+                escape(&value_text(value))
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Сохраняет граф в файл GEXF
+pub fn save_gexf<N: Node + Serialize, W: Weight + Serialize>(
+    graph: &Graph<N, W>,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    fs::write(path, to_gexf(graph))?;
+    Ok(())
+}
+
+/// Разбирает граф из GEXF. Свойство рёбер `capacity` становится
+/// пропускной способностью, остальные свойства и подписи, отличные от
+/// идентификатора, — свойствами вершин и рёбер. Оформление (`viz`)
+/// пропускается, динамические и иерархические графы не поддерживаются.
+pub fn parse_gexf<N: Node + FromStr, W: Weight + FromStr>(
+    text: &str,
+) -> Result<Graph<N, W>, XmlError> {
+    let document = Document::parse(text)?;
+    let root = document.root_element();
+    if root.tag_name().name() != "gexf" {
+        return Err(XmlError::WrongRoot {
+            found: root.tag_name().name().to_string(),
+            expected: "gexf",
+        });
+    }
+    let graph = children(root, "graph")
+        .next()
+        .ok_or(XmlError::MissingElement { element: "graph" })?;
+    if graph.attribute("mode") == Some("dynamic") {
+        return Err(XmlError::Unsupported {
+            line: line(graph),
+            construct: "dynamic graphs",
+        });
+    }
+    let default_directed = graph.attribute("defaultedgetype") == Some("directed");
+
+    let mut node_keys = Declarations::new();
+    let mut edge_keys = Declarations::new();
+    for attributes in children(graph, "attributes") {
+        let keys = match attributes.attribute("class") {
+            Some("edge") => &mut edge_keys,
+            _ => &mut node_keys,
+        };
+        for attribute in children(attributes, "attribute") {
+            let id = required(attribute, "id")?;
+            let mut declared = Declared {
+                name: attribute.attribute("title").unwrap_or(id).to_string(),
+                kind: AttrType::parse_name(attribute.attribute("type").unwrap_or("string")),
+                default: None,
+            };
+            if let Some(default) = children(attribute, "default").next() {
+                declared.default = Some(parse_value(
+                    default,
+                    &declared,
+                    default.text().unwrap_or(""),
+                )?);
+            }
+            keys.insert(id.to_string(), declared);
+        }
+    }
+
+    let mut nodes: BTreeMap<N, Attributes> = BTreeMap::new();
+    for element in children(graph, "nodes").flat_map(|nodes| children(nodes, "node")) {
+        if let Some(inner) = children(element, "nodes").next() {
+            return Err(XmlError::Unsupported {
+                line: line(inner),
+                construct: "hierarchical graphs",
+            });
+        }
+        let id = required(element, "id")?;
+        let node = parse_node(element, id)?;
+        let mut attributes = read_values(element, &node_keys)?;
+        if let Some(label) = element.attribute("label").filter(|label| *label != id) {
+            attributes.insert("label".to_string(), label.into());
+        }
+        nodes.entry(node).or_default().extend(attributes);
+    }
+
+    let mut links: Vec<Link<N, W>> = Vec::new();
+    let mut any_directed = false;
+    for element in children(graph, "edges").flat_map(|edges| children(edges, "edge")) {
+        let from: N = parse_node(element, required(element, "source")?)?;
+        let to: N = parse_node(element, required(element, "target")?)?;
+        let directed = match element.attribute("type") {
+            Some(kind) => kind == "directed",
+            None => default_directed,
+        };
+        any_directed |= directed;
+
+        let weight = match element.attribute("weight") {
+            Some(value) => parse_number(element, "weight", value)?,
+            None => W::one(),
+        };
+        let mut attributes = read_values(element, &edge_keys)?;
+        let capacity = match attributes.remove("capacity") {
+            Some(value) => parse_number(element, "capacity", &value_text(&value))?,
+            None => W::one(),
+        };
+        if let Some(label) = element.attribute("label") {
+            attributes.insert("label".to_string(), label.into());
+        }
+
+        nodes.entry(from.clone()).or_default();
+        nodes.entry(to.clone()).or_default();
+        let mut edge = Edge::value(to).weight(weight).capacity(capacity).build();
+        edge.attributes = attributes;
+        links.push((from, edge, !directed));
+    }
+
+    Ok(assemble(
+        default_directed || any_directed,
+        nodes,
+        links,
+        false,
+    ))
+}
+
+// Значения свойств элемента с учётом значений по умолчанию
+fn read_values(element: XmlNode, keys: &Declarations) -> Result<Attributes, XmlError> {
+    let mut attributes: Attributes = keys
+        .values()
+        .filter_map(|declared| Some((declared.name.clone(), declared.default.clone()?)))
+        .collect();
+
+    for value in children(element, "attvalues").flat_map(|values| children(values, "attvalue")) {
+        // В GEXF 1.1 ссылка на свойство называлась id
+        let key = value
+            .attribute("for")
+            .or(value.attribute("id"))
+            .ok_or_else(|| XmlError::MissingAttribute {
+                line: line(value),
+                element: "attvalue".to_string(),
+                attribute: "for",
+            })?;
+        let declared = keys.get(key).ok_or_else(|| XmlError::UnknownKey {
+            line: line(value),
+            key: key.to_string(),
+        })?;
+        let parsed = parse_value(value, declared, required(value, "value")?)?;
+        attributes.insert(declared.name.clone(), parsed);
+    }
+    Ok(attributes)
+}
+
+/// Загружает граф из файла GEXF
+pub fn load_gexf<N: Node + FromStr, W: Weight + FromStr>(
+    path: impl AsRef<Path>,
+) -> anyhow::Result<Graph<N, W>> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_gexf(&text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut graph: Graph<usize, f64> = Graph::new_multigraph(false);
+        for node in 1..=3 {
+            graph.add_node(node);
+        }
+        graph.add_rib(1, 2, 2.5, 1.0).unwrap();
+        graph.add_rib(1, 2, 3.0, 4.0).unwrap();
+        graph.add_rib(2, 3, 1.0, 1.0).unwrap();
+        graph
+            .node_attributes
+            .entry(3)
+            .or_default()
+            .insert("visited".into(), true.into());
+
+        let parsed: Graph<usize, f64> = parse_gexf(&to_gexf(&graph)).unwrap();
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            serde_json::to_string(&graph).unwrap()
+        );
+
+        let text = to_gexf(&graph).replace(r#"mode="static""#, r#"mode="dynamic""#);
+        assert!(matches!(
+            parse_gexf::<usize, f64>(&text),
+            Err(XmlError::Unsupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,366 @@
+// Импорт и экспорт формата GraphML (yEd, Gephi, NetworkX)
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use roxmltree::{Document, Node as XmlNode};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{
+    Link, assemble, serialized, value_text,
+    xml::{
+        AttrType, Declarations, Declared, XmlError, children, declare, escape, line, parse_node,
+        parse_number, parse_value, required,
+    },
+};
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Описание графа в GraphML. Вес и пропускная способность записываются
+/// свойствами `weight` и `capacity`, рёбра смешанного графа помечаются
+/// `directed="false"`.
+pub fn to_graphml<N: Node + Serialize, W: Weight + Serialize>(graph: &Graph<N, W>) -> String {
+    let links: Vec<_> = graph
+        .arcs()
+        .filter(|(from, to, edge)| !graph.is_rib(edge) || from <= to)
+        .collect();
+    let numbers = links
+        .iter()
+        .flat_map(|(_, _, edge)| [edge.weight, edge.capacity])
+        .map(|number| serde_json::to_value(number).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let number_type = if numbers.is_empty() {
+        AttrType::Double
+    } else {
+        AttrType::of(&numbers)
+    };
+
+    let node_keys = declare(graph.node_attributes.values().flatten());
+    let edge_keys: Vec<_> = declare(links.iter().flat_map(|(_, _, edge)| &edge.attributes))
+        .into_iter()
+        .filter(|(name, _)| name != "weight" && name != "capacity")
+        .collect();
+
+    let mut xml = String::new();
+    writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(
+        xml,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )
+    .unwrap();
+    for name in ["weight", "capacity"] {
+        writeln!(
+            xml,
+            r#"  <key id="{name}" for="edge" attr.name="{name}" attr.type="{}"/>"#,
+            number_type.name()
+        )
+        .unwrap();
+    }
+    for (prefix, target, keys) in [("n", "node", &node_keys), ("e", "edge", &edge_keys)] {
+        for (index, (name, kind)) in keys.iter().enumerate() {
+            writeln!(
+                xml,
+                r#"  <key id="{prefix}{index}" for="{target}" attr.name="{}" attr.type="{}"/>"#,
+                escape(name),
+                kind.name()
+            )
+            .unwrap();
+        }
+    }
+
+    let edgedefault = if graph.directed {
+        "directed"
+    } else {
+        "undirected"
+    };
+    writeln!(xml, r#"  <graph id="G" edgedefault="{edgedefault}">"#).unwrap();
+    for node in graph.nodes() {
+        let id = escape(&serialized(node));
+        match graph.node_attributes.get(node) {
+            Some(attributes) if !attributes.is_empty() => {
+                writeln!(xml, r#"    <node id="{id}">"#).unwrap();
+                write_data(&mut xml, "n", &node_keys, attributes);
+                writeln!(xml, "    </node>").unwrap();
+            }
+            _ => writeln!(xml, r#"    <node id="{id}"/>"#).unwrap(),
+        }
+    }
+    for (from, to, edge) in links {
+        write!(
+            xml,
+            r#"    <edge source="{}" target="{}""#,
+            escape(&serialized(from)),
+            escape(&serialized(to))
+        )
+        .unwrap();
+        if graph.multigraph {
+            write!(xml, r#" id="e{}""#, edge.id).unwrap();
+        }
+        if graph.directed && edge.rib {
+            write!(xml, r#" directed="false""#).unwrap();
+        }
+        writeln!(xml, ">").unwrap();
+        writeln!(
+            xml,
+            r#"      <data key="weight">{}</data>"#,
+            escape(&serialized(&edge.weight))
+        )
+        .unwrap();
+        writeln!(
+            xml,
+            r#"      <data key="capacity">{}</data>"#,
+            escape(&serialized(&edge.capacity))
+        )
+        .unwrap();
+        write_data(&mut xml, "e", &edge_keys, &edge.attributes);
+        writeln!(xml, "    </edge>").unwrap();
+    }
+    writeln!(xml, "  </graph>").unwrap();
+    writeln!(xml, "</graphml>").unwrap();
+    xml
+}
+
+fn write_data(
+    xml: &mut String,
+    prefix: &str,
+    keys: &[(String, AttrType)],
+    attributes: &Attributes,
+) {
+    for (index, (name, _)) in keys.iter().enumerate() {
+        if let Some(value) = attributes.get(name) {
+            writeln!(
+                xml,
+                r#"      <data key="{prefix}{index}">{}</data>"#,
+                escape(&value_text(value))
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Сохраняет граф в файл GraphML
+pub fn save_graphml<N: Node + Serialize, W: Weight + Serialize>(
+    graph: &Graph<N, W>,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    fs::write(path, to_graphml(graph))?;
+    Ok(())
+}
+
+/// Разбирает граф из GraphML. Читается первый граф файла; свойства с
+/// именами `weight` и `capacity` становятся весом и пропускной
+/// способностью рёбер, остальные — свойствами вершин и рёбер. Данные с
+/// вложенной разметкой (например, оформление yEd) пропускаются, вложенные
+/// графы, порты и гиперрёбра не поддерживаются.
+pub fn parse_graphml<N: Node + FromStr, W: Weight + FromStr>(
+    text: &str,
+) -> Result<Graph<N, W>, XmlError> {
+    let document = Document::parse(text)?;
+    let root = document.root_element();
+    if root.tag_name().name() != "graphml" {
+        return Err(XmlError::WrongRoot {
+            found: root.tag_name().name().to_string(),
+            expected: "graphml",
+        });
+    }
+
+    let mut node_keys = Declarations::new();
+    let mut edge_keys = Declarations::new();
+    for key in children(root, "key") {
+        let id = required(key, "id")?;
+        let kind = AttrType::parse_name(key.attribute("attr.type").unwrap_or("string"));
+        let mut declared = Declared {
+            name: key.attribute("attr.name").unwrap_or(id).to_string(),
+            kind,
+            default: None,
+        };
+        if let Some(default) = children(key, "default").next() {
+            declared.default = Some(parse_value(
+                default,
+                &declared,
+                default.text().unwrap_or(""),
+            )?);
+        }
+
+        match key.attribute("for").unwrap_or("all") {
+            "node" => {
+                node_keys.insert(id.to_string(), declared);
+            }
+            "edge" => {
+                edge_keys.insert(id.to_string(), declared);
+            }
+            "all" => {
+                node_keys.insert(id.to_string(), declared.clone());
+                edge_keys.insert(id.to_string(), declared);
+            }
+            _ => {}
+        }
+    }
+
+    let mut graphs = children(root, "graph");
+    let graph = graphs
+        .next()
+        .ok_or(XmlError::MissingElement { element: "graph" })?;
+    if let Some(extra) = graphs.next() {
+        return Err(XmlError::Unsupported {
+            line: line(extra),
+            construct: "several graphs in one file",
+        });
+    }
+    let edgedefault_directed = graph.attribute("edgedefault") != Some("undirected");
+
+    let mut nodes: BTreeMap<N, Attributes> = BTreeMap::new();
+    let mut links: Vec<Link<N, W>> = Vec::new();
+    let mut any_directed = false;
+    for element in graph.children().filter(XmlNode::is_element) {
+        match element.tag_name().name() {
+            "node" => {
+                for (name, construct) in [("graph", "nested graphs"), ("port", "ports")] {
+                    if let Some(inner) = children(element, name).next() {
+                        return Err(XmlError::Unsupported {
+                            line: line(inner),
+                            construct,
+                        });
+                    }
+                }
+                let node = parse_node(element, required(element, "id")?)?;
+                let attributes = read_data(element, &node_keys)?;
+                nodes.entry(node).or_default().extend(attributes);
+            }
+            "edge" => {
+                let from: N = parse_node(element, required(element, "source")?)?;
+                let to: N = parse_node(element, required(element, "target")?)?;
+                let directed = match element.attribute("directed") {
+                    Some(value) => value == "true" || value == "1",
+                    None => edgedefault_directed,
+                };
+                any_directed |= directed;
+
+                let mut attributes = read_data(element, &edge_keys)?;
+                let mut number = |name: &str| -> Result<W, XmlError> {
+                    match attributes.remove(name) {
+                        Some(value) => parse_number(element, name, &value_text(&value)),
+                        None => Ok(W::one()),
+                    }
+                };
+                let weight = number("weight")?;
+                let capacity = number("capacity")?;
+
+                nodes.entry(from.clone()).or_default();
+                nodes.entry(to.clone()).or_default();
+                let mut edge = Edge::value(to).weight(weight).capacity(capacity).build();
+                edge.attributes = attributes;
+                links.push((from, edge, !directed));
+            }
+            "hyperedge" => {
+                return Err(XmlError::Unsupported {
+                    line: line(element),
+                    construct: "hyperedges",
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(assemble(
+        edgedefault_directed || any_directed,
+        nodes,
+        links,
+        false,
+    ))
+}
+
+// Свойства элемента с учётом значений по умолчанию
+fn read_data(element: XmlNode, keys: &Declarations) -> Result<Attributes, XmlError> {
+    let mut attributes: Attributes = keys
+        .values()
+        .filter_map(|declared| Some((declared.name.clone(), declared.default.clone()?)))
+        .collect();
+    let mut skipped = BTreeSet::new();
+
+    for data in children(element, "data") {
+        let key = required(data, "key")?;
+        let declared = keys.get(key).ok_or_else(|| XmlError::UnknownKey {
+            line: line(data),
+            key: key.to_string(),
+        })?;
+        // Вложенная разметка — оформление редактора, а не значение
+        if data.children().any(|child| child.is_element()) {
+            skipped.insert(declared.name.clone());
+            continue;
+        }
+        let value: Value = parse_value(data, declared, data.text().unwrap_or(""))?;
+        attributes.insert(declared.name.clone(), value);
+    }
+
+    attributes.retain(|name, _| !skipped.contains(name));
+    Ok(attributes)
+}
+
+/// Загружает граф из файла GraphML
+pub fn load_graphml<N: Node + FromStr, W: Weight + FromStr>(
+    path: impl AsRef<Path>,
+) -> anyhow::Result<Graph<N, W>> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_graphml(&text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut graph: Graph<String> = Graph::new_mixed();
+        for node in ["A", "B", "C & D"] {
+            graph.add_node(node.to_string());
+        }
+        graph.add_rib("A".into(), "B".into(), 2, 1).unwrap();
+        graph.add_edge("B".into(), "C & D".into(), 3, 5).unwrap();
+        graph
+            .node_attributes
+            .entry("A".into())
+            .or_default()
+            .insert("color".into(), "red".into());
+
+        let parsed: Graph<String> = parse_graphml(&to_graphml(&graph)).unwrap();
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            serde_json::to_string(&graph).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_foreign_file() {
+        let text = r#"<?xml version="1.0"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="edge" attr.name="weight" attr.type="double"><default>1.5</default></key>
+  <key id="d1" for="node" attr.name="size" attr.type="int"/>
+  <key id="d2" for="node" yfiles.type="nodegraphics"/>
+  <graph edgedefault="undirected">
+    <node id="1"><data key="d1">10</data><data key="d2"><shape/></data></node>
+    <node id="2"/>
+    <edge source="1" target="2"/>
+    <edge source="2" target="1"><data key="d0">4</data></edge>
+  </graph>
+</graphml>"#;
+        let graph: Graph<usize, f64> = parse_graphml(text).unwrap();
+        assert!(!graph.directed && graph.multigraph);
+        assert_eq!(graph.node_attributes[&1]["size"], 10);
+        let weights: Vec<f64> = graph.out_edges(&1).map(|edge| edge.weight).collect();
+        assert_eq!(weights, [1.5, 4.0]);
+
+        assert!(matches!(
+            parse_graphml::<usize, f64>(&text.replace("<node id=\"2\"/>", "<hyperedge/>")),
+            Err(XmlError::Unsupported { line: 8, .. })
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+// Матрицы смежности (весов) и инцидентности в текстовом виде
+
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path, str::FromStr};
+
+use bon::Builder;
+use thiserror::Error;
+
+use super::{Link, assemble};
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Настройки матрицы смежности
+#[derive(Clone, Debug, Builder)]
+pub struct Matrix {
+    /// Обозначение отсутствия ребра, например `-`, `0` или `∞`
+    #[builder(into, default = "-".to_string())]
+    pub no_edge: String,
+
+    /// Ориентированность при чтении. Без значения граф ориентирован, если
+    /// матрица несимметрична.
+    pub directed: Option<bool>,
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum MatrixError {
+    #[error("Line {line}: expected {expected} cells, found {found}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Line {line}: row {found} does not match column {expected}")]
+    LabelMismatch {
+        line: usize,
+        expected: String,
+        found: String,
+    },
+
+    #[error("Line {line}: {id} is not a valid node id")]
+    InvalidNode { line: usize, id: String },
+
+    #[error("Line {line}: {value} is not a number")]
+    InvalidNumber { line: usize, value: String },
+
+    #[error("Matrix is not symmetric: {from} -> {to}")]
+    Asymmetric { from: String, to: String },
+
+    #[error("Column {column} must contain 1 and -1, two ones or a single 2, the rest being zeros")]
+    InvalidIncidence { column: usize },
+
+    #[error("Parallel edges {from} -> {to} cannot be written as an adjacency matrix")]
+    ParallelEdges { from: String, to: String },
+}
+
+// Строка таблицы: номер строки файла, подпись и ячейки
+struct Row<'a> {
+    line: usize,
+    label: Option<&'a str>,
+    cells: Vec<&'a str>,
+}
+
+// Разбивает текст на строки таблицы. Если в первой строке на одну ячейку
+// меньше, чем во второй, она считается заголовком, а остальные строки
+// начинаются с подписи. Все строки должны быть одной длины.
+fn table(text: &str) -> Result<(Option<Vec<&str>>, Vec<Row<'_>>), MatrixError> {
+    let mut rows: Vec<(usize, Vec<&str>)> = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let cells = line
+                .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+                .filter(|cell| !cell.is_empty())
+                .collect();
+            (index + 1, cells)
+        })
+        .filter(|(_, cells): &(usize, Vec<&str>)| {
+            cells.first().is_some_and(|first| !first.starts_with('#'))
+        })
+        .collect();
+
+    let header =
+        (rows.len() >= 2 && rows[0].1.len() + 1 == rows[1].1.len()).then(|| rows.remove(0).1);
+    let width = match &header {
+        Some(header) => header.len() + 1,
+        None => rows.first().map_or(0, |(_, cells)| cells.len()),
+    };
+
+    let mut table = Vec::new();
+    for (line, mut cells) in rows {
+        if cells.len() != width {
+            return Err(MatrixError::Ragged {
+                line,
+                expected: width,
+                found: cells.len(),
+            });
+        }
+        let label = header.is_some().then(|| cells.remove(0));
+        table.push(Row { line, label, cells });
+    }
+    Ok((header, table))
+}
+
+// Вершины по подписям строк или номера с 1, если подписей нет
+fn row_nodes<N: FromStr>(rows: &[Row]) -> Result<Vec<N>, MatrixError> {
+    rows.iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let id = row.label.map_or((index + 1).to_string(), str::to_string);
+            id.parse()
+                .map_err(|_| MatrixError::InvalidNode { line: row.line, id })
+        })
+        .collect()
+}
+
+/// Разбирает квадратную матрицу весов. Строки и столбцы могут быть
+/// подписаны именами вершин, иначе вершины нумеруются с 1. Ячейки
+/// разделяются пробелами, запятыми или `;`, `no_edge` обозначает
+/// отсутствие ребра и на диагонали тоже, поэтому с `no_edge` равным `0`
+/// нулевая диагональ не даёт петель; другое значение на диагонали — петля.
+/// Симметричная матрица без явной ориентированности даёт неориентированный
+/// граф.
+pub fn parse_adjacency_matrix<N: Node + FromStr, W: Weight + FromStr>(
+    text: &str,
+    options: &Matrix,
+) -> Result<Graph<N, W>, MatrixError> {
+    let (header, rows) = table(text)?;
+    for (row, expected) in rows.iter().zip(header.iter().flatten()) {
+        if row.label != Some(expected) {
+            return Err(MatrixError::LabelMismatch {
+                line: row.line,
+                expected: expected.to_string(),
+                found: row.label.unwrap_or_default().to_string(),
+            });
+        }
+    }
+    if let Some(row) = rows.iter().find(|row| row.cells.len() != rows.len()) {
+        return Err(MatrixError::Ragged {
+            line: row.line,
+            expected: rows.len(),
+            found: row.cells.len(),
+        });
+    }
+    let nodes: Vec<N> = row_nodes(&rows)?;
+
+    let mut weights: Vec<Vec<Option<W>>> = Vec::new();
+    for row in &rows {
+        let parsed = row
+            .cells
+            .iter()
+            .map(|&cell| match cell {
+                _ if cell == options.no_edge => Ok(None),
+                _ => cell
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| MatrixError::InvalidNumber {
+                        line: row.line,
+                        value: cell.to_string(),
+                    }),
+            })
+            .collect::<Result<_, _>>()?;
+        weights.push(parsed);
+    }
+
+    let asymmetric = (0..nodes.len())
+        .flat_map(|i| (0..nodes.len()).map(move |j| (i, j)))
+        .find(|&(i, j)| match (weights[i][j], weights[j][i]) {
+            (Some(first), Some(second)) => first.total_cmp(&second).is_ne(),
+            (first, second) => first.is_some() != second.is_some(),
+        });
+    let directed = options.directed.unwrap_or(asymmetric.is_some());
+    if let (false, Some((i, j))) = (directed, asymmetric) {
+        let name = |k: usize| rows[k].label.map_or((k + 1).to_string(), str::to_string);
+        return Err(MatrixError::Asymmetric {
+            from: name(i),
+            to: name(j),
+        });
+    }
+
+    let mut links: Vec<Link<N, W>> = Vec::new();
+    for (i, row) in weights.iter().enumerate() {
+        for (j, weight) in row.iter().enumerate() {
+            let Some(weight) = *weight else {
+                continue;
+            };
+            if directed || i <= j {
+                let edge = Edge::value(nodes[j].clone()).weight(weight).build();
+                links.push((nodes[i].clone(), edge, !directed));
+            }
+        }
+    }
+
+    let nodes = nodes
+        .into_iter()
+        .map(|node| (node, Attributes::new()))
+        .collect();
+    Ok(assemble(directed, nodes, links, true))
+}
+
+/// Матрица весов с подписанными строками и столбцами, выровненная по
+/// ширине. Ребро смешанного графа записывается в обе стороны. Кратные
+/// рёбра записать нельзя.
+pub fn to_adjacency_matrix<N: Node + Display, W: Weight + Display>(
+    graph: &Graph<N, W>,
+    options: &Matrix,
+) -> Result<String, MatrixError> {
+    let nodes: Vec<&N> = graph.nodes().collect();
+    let mut cells: BTreeMap<(&N, &N), String> = BTreeMap::new();
+    for (from, to, edge) in graph.arcs() {
+        if cells.insert((from, to), edge.weight.to_string()).is_some() {
+            return Err(MatrixError::ParallelEdges {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        }
+    }
+
+    let mut table = vec![
+        std::iter::once(String::new())
+            .chain(nodes.iter().map(|node| node.to_string()))
+            .collect::<Vec<_>>(),
+    ];
+    for from in &nodes {
+        let mut row = vec![from.to_string()];
+        row.extend(nodes.iter().map(|to| {
+            cells
+                .get(&(*from, *to))
+                .cloned()
+                .unwrap_or_else(|| options.no_edge.clone())
+        }));
+        table.push(row);
+    }
+    Ok(align(table))
+}
+
+/// Разбирает матрицу инцидентности: строки — вершины, столбцы — рёбра.
+/// В столбце дуги стоят 1 у начала и -1 у конца, в столбце ребра — две
+/// единицы, в столбце петли — 2. Граф ориентирован, если есть хотя бы одна
+/// дуга; столбцы из двух единиц в нём становятся рёбрами смешанного графа.
+/// Повторяющиеся столбцы делают граф мультиграфом. Веса не хранятся.
+pub fn parse_incidence_matrix<N: Node + FromStr, W: Weight>(
+    text: &str,
+) -> Result<Graph<N, W>, MatrixError> {
+    let (_, rows) = table(text)?;
+    let nodes: Vec<N> = row_nodes(&rows)?;
+    let columns = rows.first().map_or(0, |row| row.cells.len());
+
+    let mut ends: Vec<Vec<(usize, i8)>> = vec![Vec::new(); columns];
+    for (i, row) in rows.iter().enumerate() {
+        for (column, &cell) in row.cells.iter().enumerate() {
+            let value: i8 = cell.parse().map_err(|_| MatrixError::InvalidNumber {
+                line: row.line,
+                value: cell.to_string(),
+            })?;
+            if value != 0 {
+                ends[column].push((i, value));
+            }
+        }
+    }
+
+    let directed = ends.iter().flatten().any(|&(_, value)| value == -1);
+    let mut links: Vec<Link<N, W>> = Vec::new();
+    for (column, ends) in ends.iter().enumerate() {
+        let (from, to, rib) = match ends.as_slice() {
+            &[(node, 2)] => (node, node, !directed),
+            &[(from, 1), (to, -1)] | &[(to, -1), (from, 1)] => (from, to, false),
+            &[(first, 1), (second, 1)] => (first, second, true),
+            _ => return Err(MatrixError::InvalidIncidence { column: column + 1 }),
+        };
+        let edge = Edge::value(nodes[to].clone()).build();
+        links.push((nodes[from].clone(), edge, rib));
+    }
+
+    let nodes = nodes
+        .into_iter()
+        .map(|node| (node, Attributes::new()))
+        .collect();
+    Ok(assemble(directed, nodes, links, false))
+}
+
+/// Матрица инцидентности со столбцами `e1`, `e2`, ... по одному на ребро
+pub fn to_incidence_matrix<N: Node + Display, W: Weight>(graph: &Graph<N, W>) -> String {
+    let links: Vec<_> = graph
+        .arcs()
+        .filter(|(from, to, edge)| !graph.is_rib(edge) || from <= to)
+        .collect();
+
+    let mut table = vec![
+        std::iter::once(String::new())
+            .chain((1..=links.len()).map(|i| format!("e{i}")))
+            .collect::<Vec<_>>(),
+    ];
+    for node in graph.nodes() {
+        let mut row = vec![node.to_string()];
+        row.extend(links.iter().map(|&(from, to, edge)| {
+            let value = match (from == node, to == node) {
+                (true, true) => 2,
+                (true, false) => 1,
+                (false, true) if graph.is_rib(edge) => 1,
+                (false, true) => -1,
+                (false, false) => 0,
+            };
+            value.to_string()
+        }));
+        table.push(row);
+    }
+    align(table)
+}
+
+// Выравнивает столбцы по правому краю
+fn align(table: Vec<Vec<String>>) -> String {
+    let columns = table.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            table
+                .iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let mut text = String::new();
+    for row in table {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:>width$}"))
+            .collect();
+        text.push_str(cells.join(" ").trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Загружает граф из файла с матрицей весов
+pub fn load_adjacency_matrix<N: Node + FromStr, W: Weight + FromStr>(
+    path: impl AsRef<Path>,
+    options: &Matrix,
+) -> anyhow::Result<Graph<N, W>> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_adjacency_matrix(&text, options)?)
+}
+
+/// Сохраняет граф матрицей весов
+pub fn save_adjacency_matrix<N: Node + Display, W: Weight + Display>(
+    graph: &Graph<N, W>,
+    path: impl AsRef<Path>,
+    options: &Matrix,
+) -> anyhow::Result<()> {
+    fs::write(path, to_adjacency_matrix(graph, options)?)?;
+    Ok(())
+}
+
+/// Загружает граф из файла с матрицей инцидентности
+pub fn load_incidence_matrix<N: Node + FromStr, W: Weight>(
+    path: impl AsRef<Path>,
+) -> anyhow::Result<Graph<N, W>> {
+    let text = fs::read_to_string(path)?;
+    Ok(parse_incidence_matrix(&text)?)
+}
+
+/// Сохраняет граф матрицей инцидентности
+pub fn save_incidence_matrix<N: Node + Display, W: Weight>(
+    graph: &Graph<N, W>,
+    path: impl AsRef<Path>,
+) -> anyhow::Result<()> {
+    fs::write(path, to_incidence_matrix(graph))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacency_matrix() {
+        let text = "# задача 3\n∞ 8 12\n8 ∞ 7\n12 7 ∞\n";
+        let options = Matrix::builder().no_edge("∞").build();
+        let graph: Graph<usize> = parse_adjacency_matrix(text, &options).unwrap();
+
+        assert!(!graph.directed);
+        assert_eq!(
+            graph
+                .out_edges(&3)
+                .map(|edge| edge.weight)
+                .collect::<Vec<_>>(),
+            [12, 7]
+        );
+        assert_eq!(
+            to_adjacency_matrix(&graph, &options).unwrap(),
+            "   1 2  3\n1  ∞ 8 12\n2  8 ∞  7\n3 12 7  ∞\n"
+        );
+
+        let text = "  A B\nA - 1\nB - -\n";
+        let directed: Graph<String> =
+            parse_adjacency_matrix(text, &Matrix::builder().build()).unwrap();
+        assert!(directed.directed);
+        assert_eq!(directed.out_edges(&"A".to_string()).count(), 1);
+        assert!(matches!(
+            parse_adjacency_matrix::<String, i32>(text, &Matrix::builder().directed(false).build()),
+            Err(MatrixError::Asymmetric { .. })
+        ));
+    }
+
+    #[test]
+    fn test_zero_diagonal() {
+        let text = "0 3 0\n3 0 5\n0 5 2\n";
+        let options = Matrix::builder().no_edge("0").build();
+        let graph: Graph<usize> = parse_adjacency_matrix(text, &options).unwrap();
+
+        assert!(!graph.directed);
+        assert_eq!(graph.arcs().filter(|(from, to, _)| from == to).count(), 1);
+        assert_eq!(graph.degree(&1), 1);
+        assert_eq!(
+            graph
+                .out_edges(&3)
+                .map(|edge| edge.node)
+                .collect::<Vec<_>>(),
+            [2, 3]
+        );
+        assert_eq!(
+            to_adjacency_matrix(&graph, &options).unwrap(),
+            "  1 2 3\n1 0 3 0\n2 3 0 5\n3 0 5 2\n"
+        );
+    }
+
+    #[test]
+    fn test_incidence_matrix() {
+        let mut graph: Graph<char> = Graph::new_mixed();
+        graph.multigraph = true;
+        for node in 'A'..='C' {
+            graph.add_node(node);
+        }
+        graph.add_edge('A', 'B', 1, 1).unwrap();
+        graph.add_edge('A', 'B', 1, 1).unwrap();
+        graph.add_rib('B', 'C', 1, 1).unwrap();
+        graph.add_edge('C', 'C', 1, 1).unwrap();
+
+        let text = to_incidence_matrix(&graph);
+        assert_eq!(
+            text,
+            "  e1 e2 e3 e4\nA  1  1  0  0\nB -1 -1  1  0\nC  0  0  1  2\n"
+        );
+        let parsed: Graph<char> = parse_incidence_matrix(&text).unwrap();
+        assert_eq!(to_incidence_matrix(&parsed), text);
+        assert!(parsed.mixed && parsed.multigraph);
+
+        assert!(matches!(
+            parse_incidence_matrix::<char, i32>("  e1\nA 1\nB 0\n"),
+            Err(MatrixError::InvalidIncidence { column: 1 })
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+pub mod dot;
+pub mod edge_list;
+pub mod gexf;
+pub mod graphml;
+pub mod matrix;
+pub mod xml;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs::File,
+    path::Path,
+    str::FromStr,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::{
+    graph::{Attributes, Edge, Graph, Node},
+    validate::{SelfLoops, ValidationError, validate},
+    weight::Weight,
+};
+
+/// Сохраняет граф в формате, выбранном по расширению файла: `.dot` и `.gv`
+/// (без выделения), `.graphml`, `.gexf` или JSON для остальных. Те же
+/// расширения распознаёт [`load_graph`].
+pub fn save_graph<N, W>(graph: &Graph<N, W>, path: impl AsRef<Path>) -> anyhow::Result<()>
+where
+    N: Node + Serialize + Display,
+    W: Weight + Serialize + Display,
+{
+    match extension(path.as_ref()) {
+        Some("dot" | "gv") => return dot::save_dot(graph, &dot::Highlight::default(), path),
+        Some("graphml") => return graphml::save_graphml(graph, path),
+        Some("gexf") => return gexf::save_gexf(graph, path),
+        _ => {}
+    }
+
+    let file = File::create(path)?;
+    serde_json::to_writer_pretty(file, graph)?;
+    Ok(())
+}
+
+/// Загружает граф и проверяет его структуру. Если в файле есть висячие дуги,
+/// несимметричные рёбра или отрицательные пропускные способности, возвращается
+/// [`ValidationError`] со списком всех проблем.
+pub fn load_graph<N, W>(path: impl AsRef<Path>) -> anyhow::Result<Graph<N, W>>
+where
+    N: Node + DeserializeOwned + FromStr + Send + Sync + 'static,
+    W: Weight + DeserializeOwned + FromStr + Send + Sync + 'static,
+{
+    let graph = load_graph_unchecked(path)?;
+    let problems = validate(&graph, SelfLoops::Allow);
+    if !problems.is_empty() {
+        return Err(ValidationError(problems).into());
+    }
+    Ok(graph)
+}
+
+/// Загружает граф без проверки структуры, например чтобы потом исправить его
+/// при помощи [`crate::validate::repair`]. Формат выбирается по расширению:
+/// `.dot` и `.gv`, `.graphml`, `.gexf`, остальные файлы читаются как JSON.
+pub fn load_graph_unchecked<N, W>(path: impl AsRef<Path>) -> anyhow::Result<Graph<N, W>>
+where
+    N: Node + DeserializeOwned + FromStr,
+    W: Weight + DeserializeOwned + FromStr,
+{
+    match extension(path.as_ref()) {
+        Some("dot" | "gv") => return dot::load_dot(path),
+        Some("graphml") => return graphml::load_graphml(path),
+        Some("gexf") => return gexf::load_gexf(path),
+        _ => {}
+    }
+
+    let file = File::open(path)?;
+    let graph = serde_json::from_reader(file)?;
+    Ok(graph)
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|extension| extension.to_str())
+}
+
+// Ребро или дуга, прочитанные из файла: начало, ребро и признак ребра
+type Link<N, W> = (N, Edge<N, W>, bool);
+
+// Собирает граф из прочитанных вершин и рёбер. Кратные рёбра делают граф
+// мультиграфом, а при `merge` повторное ребро заменяет предыдущее
+fn assemble<N: Node, W: Weight>(
+    directed: bool,
+    nodes: BTreeMap<N, Attributes>,
+    links: Vec<Link<N, W>>,
+    merge: bool,
+) -> Graph<N, W> {
+    let mut seen = BTreeSet::new();
+    let duplicates = links.iter().any(|(from, edge, rib)| {
+        let (a, b) = (from.clone(), edge.node.clone());
+        let repeated = !seen.insert((a.clone(), b.clone()));
+        repeated || (*rib && a != b && !seen.insert((b, a)))
+    });
+    let multigraph = duplicates && !merge;
+
+    let mut edges: BTreeMap<N, BTreeSet<Edge<N, W>>> = nodes
+        .keys()
+        .map(|node| (node.clone(), BTreeSet::new()))
+        .collect();
+    for (id, (from, mut edge, rib)) in links.into_iter().enumerate() {
+        if multigraph {
+            edge.id = id + 1;
+        }
+        edge.rib = rib && directed;
+        if rib {
+            let mut half = edge.clone();
+            half.node = from.clone();
+            edges.entry(edge.node.clone()).or_default().replace(half);
+        }
+        edges.entry(from).or_default().replace(edge);
+    }
+
+    let mixed = directed && edges.values().flatten().any(|edge| edge.rib);
+    let mut graph = Graph::from_edges(directed, edges);
+    graph.multigraph = multigraph;
+    graph.mixed = mixed;
+    graph.node_attributes = nodes
+        .into_iter()
+        .filter(|(_, attributes)| !attributes.is_empty())
+        .collect();
+    graph
+}
+
+// Значение свойства из текстового формата: число или логическое значение,
+// если оно так выглядит, иначе строка
+fn guess_value(value: String) -> Value {
+    match serde_json::from_str(&value) {
+        Ok(parsed @ (Value::Number(_) | Value::Bool(_))) => parsed,
+        _ => Value::String(value),
+    }
+}
+
+// Текст значения свойства: строки без кавычек, остальное как в JSON
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+// Текст идентификатора вершины или числа через их JSON-представление
+fn serialized<T: Serialize>(value: &T) -> String {
+    value_text(&serde_json::to_value(value).expect("node ids and weights are serializable"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load_by_extension() {
+        let mut graph: Graph<String, f64> = Graph::new_mixed();
+        for node in ["A", "B", "C"] {
+            graph.add_node(node.to_string());
+        }
+        graph.add_rib("A".into(), "B".into(), 2.5, 1.0).unwrap();
+        graph.add_edge("B".into(), "C".into(), 3.0, 4.0).unwrap();
+
+        for extension in ["json", "dot", "gv", "graphml", "gexf"] {
+            let path =
+                std::env::temp_dir().join(format!("save-{}.{extension}", std::process::id()));
+            save_graph(&graph, &path).unwrap();
+            let loaded: Graph<String, f64> = load_graph(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(
+                serde_json::to_string(&loaded).unwrap(),
+                serde_json::to_string(&graph).unwrap(),
+                "{extension}"
+            );
+        }
+    }
+}
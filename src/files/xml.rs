@@ -0,0 +1,210 @@
+// Общее для форматов GraphML и GEXF: ошибки чтения, экранирование и типы
+// свойств
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use roxmltree::Node as XmlNode;
+use serde_json::Value;
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error)]
+pub enum XmlError {
+    #[error(transparent)]
+    Syntax(#[from] roxmltree::Error),
+
+    #[error("Root element is <{found}>, expected <{expected}>")]
+    WrongRoot {
+        found: String,
+        expected: &'static str,
+    },
+
+    #[error("Element <{element}> is missing")]
+    MissingElement { element: &'static str },
+
+    #[error("Line {line}: element <{element}> has no attribute {attribute}")]
+    MissingAttribute {
+        line: u32,
+        element: String,
+        attribute: &'static str,
+    },
+
+    #[error("Line {line}: {construct} are not supported")]
+    Unsupported { line: u32, construct: &'static str },
+
+    #[error("Line {line}: attribute {key} is not declared")]
+    UnknownKey { line: u32, key: String },
+
+    #[error("Line {line}: {id} is not a valid node id")]
+    InvalidNode { line: u32, id: String },
+
+    #[error("Line {line}: value {value} of {key} is not a valid {kind}")]
+    InvalidValue {
+        line: u32,
+        key: String,
+        value: String,
+        kind: &'static str,
+    },
+}
+
+/// Тип свойства в объявлении ключа
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum AttrType {
+    Boolean,
+    Long,
+    Double,
+    String,
+}
+
+impl AttrType {
+    // Самый узкий тип, в который помещаются все значения свойства
+    pub(super) fn of<'a>(values: impl IntoIterator<Item = &'a Value>) -> Self {
+        values
+            .into_iter()
+            .map(|value| match value {
+                Value::Bool(_) => AttrType::Boolean,
+                Value::Number(number) if number.is_i64() || number.is_u64() => AttrType::Long,
+                Value::Number(_) => AttrType::Double,
+                _ => AttrType::String,
+            })
+            .reduce(|first, second| match (first, second) {
+                _ if first == second => first,
+                (AttrType::Long, AttrType::Double) | (AttrType::Double, AttrType::Long) => {
+                    AttrType::Double
+                }
+                _ => AttrType::String,
+            })
+            .unwrap_or(AttrType::String)
+    }
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            AttrType::Boolean => "boolean",
+            AttrType::Long => "long",
+            AttrType::Double => "double",
+            AttrType::String => "string",
+        }
+    }
+
+    pub(super) fn parse_name(name: &str) -> Self {
+        match name {
+            "boolean" => AttrType::Boolean,
+            "int" | "integer" | "long" => AttrType::Long,
+            "float" | "double" => AttrType::Double,
+            _ => AttrType::String,
+        }
+    }
+
+    // Значение свойства или None, если текст не подходит под тип
+    pub(super) fn parse(self, text: &str) -> Option<Value> {
+        match self {
+            AttrType::String => Some(Value::String(text.to_string())),
+            AttrType::Boolean => text.trim().parse::<bool>().ok().map(Value::Bool),
+            AttrType::Long => text.trim().parse::<i64>().ok().map(Value::from),
+            AttrType::Double => text
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number),
+        }
+    }
+}
+
+/// Объявленное свойство: имя, тип и значение по умолчанию
+#[derive(Clone, Debug)]
+pub(super) struct Declared {
+    pub(super) name: String,
+    pub(super) kind: AttrType,
+    pub(super) default: Option<Value>,
+}
+
+pub(super) type Declarations = BTreeMap<String, Declared>;
+
+// Имена и типы свойств вершин или рёбер для объявления в файле
+pub(super) fn declare<'a>(
+    attributes: impl IntoIterator<Item = (&'a String, &'a Value)>,
+) -> Vec<(String, AttrType)> {
+    let mut values: BTreeMap<&String, Vec<&Value>> = BTreeMap::new();
+    for (key, value) in attributes {
+        values.entry(key).or_default().push(value);
+    }
+    values
+        .into_iter()
+        .map(|(key, values)| (key.clone(), AttrType::of(values)))
+        .collect()
+}
+
+pub(super) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub(super) fn line(node: XmlNode) -> u32 {
+    node.document().text_pos_at(node.range().start).row
+}
+
+pub(super) fn required<'a>(
+    node: XmlNode<'a, '_>,
+    attribute: &'static str,
+) -> Result<&'a str, XmlError> {
+    node.attribute(attribute)
+        .ok_or_else(|| XmlError::MissingAttribute {
+            line: line(node),
+            element: node.tag_name().name().to_string(),
+            attribute,
+        })
+}
+
+pub(super) fn children<'a, 'input>(
+    node: XmlNode<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = XmlNode<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+pub(super) fn parse_node<N: FromStr>(node: XmlNode, id: &str) -> Result<N, XmlError> {
+    id.parse().map_err(|_| XmlError::InvalidNode {
+        line: line(node),
+        id: id.to_string(),
+    })
+}
+
+pub(super) fn parse_number<W: FromStr>(
+    node: XmlNode,
+    key: &str,
+    value: &str,
+) -> Result<W, XmlError> {
+    value.trim().parse().map_err(|_| XmlError::InvalidValue {
+        line: line(node),
+        key: key.to_string(),
+        value: value.to_string(),
+        kind: "number",
+    })
+}
+
+pub(super) fn parse_value(
+    node: XmlNode,
+    declared: &Declared,
+    value: &str,
+) -> Result<Value, XmlError> {
+    declared
+        .kind
+        .parse(value)
+        .ok_or_else(|| XmlError::InvalidValue {
+            line: line(node),
+            key: declared.name.clone(),
+            value: value.to_string(),
+            kind: declared.kind.name(),
+        })
+}
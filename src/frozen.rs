@@ -0,0 +1,187 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use crate::{
+    graph::{Graph, Node},
+    weight::Weight,
+};
+
+/// Неизменяемый снимок графа в сжатом виде (CSR).
+///
+/// Вершины пронумерованы подряд от нуля, дуги каждой вершины лежат в общих
+/// массивах непрерывным отрезком. Для входящих дуг хранится отдельный
+/// обратный индекс. Рёбра неориентированного графа представлены двумя дугами.
+#[derive(Clone, Debug)]
+pub struct FrozenGraph<N: Node, W: Weight = i32> {
+    nodes: Vec<N>,
+    index: BTreeMap<N, usize>,
+
+    offsets: Vec<usize>,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<W>,
+    capacities: Vec<W>,
+
+    in_offsets: Vec<usize>,
+    in_arcs: Vec<usize>,
+}
+
+impl<N: Node, W: Weight> Graph<N, W> {
+    /// Строит сжатый снимок графа для тяжёлых алгоритмов
+    pub fn freeze(&self) -> FrozenGraph<N, W> {
+        let nodes: Vec<N> = self.nodes().cloned().collect();
+        let index: BTreeMap<N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (node.clone(), idx))
+            .collect();
+
+        let arc_count = self.nodes().map(|node| self.out_degree(node)).sum();
+        let mut offsets = Vec::with_capacity(nodes.len() + 1);
+        let mut sources = Vec::with_capacity(arc_count);
+        let mut targets = Vec::with_capacity(arc_count);
+        let mut weights = Vec::with_capacity(arc_count);
+        let mut capacities = Vec::with_capacity(arc_count);
+        let mut in_counts = vec![0; nodes.len()];
+
+        offsets.push(0);
+        for (from, node) in nodes.iter().enumerate() {
+            for edge in self.out_edges(node) {
+                let to = index[&edge.node];
+                sources.push(from);
+                targets.push(to);
+                weights.push(edge.weight);
+                capacities.push(edge.capacity);
+                in_counts[to] += 1;
+            }
+            offsets.push(targets.len());
+        }
+
+        let mut in_offsets = Vec::with_capacity(nodes.len() + 1);
+        in_offsets.push(0);
+        for count in &in_counts {
+            in_offsets.push(in_offsets.last().unwrap() + count);
+        }
+
+        let mut next = in_offsets.clone();
+        let mut in_arcs = vec![0; arc_count];
+        for (arc, &to) in targets.iter().enumerate() {
+            in_arcs[next[to]] = arc;
+            next[to] += 1;
+        }
+
+        FrozenGraph {
+            nodes,
+            index,
+            offsets,
+            sources,
+            targets,
+            weights,
+            capacities,
+            in_offsets,
+            in_arcs,
+        }
+    }
+}
+
+impl<N: Node, W: Weight> FrozenGraph<N, W> {
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn arc_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Номер вершины в снимке
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    /// Исходный идентификатор вершины по её номеру
+    pub fn node(&self, idx: usize) -> &N {
+        &self.nodes[idx]
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Номера дуг, выходящих из вершины
+    pub fn out_arcs(&self, node: usize) -> Range<usize> {
+        self.offsets[node]..self.offsets[node + 1]
+    }
+
+    /// Номера дуг, входящих в вершину
+    pub fn in_arcs(&self, node: usize) -> &[usize] {
+        &self.in_arcs[self.in_offsets[node]..self.in_offsets[node + 1]]
+    }
+
+    pub fn successors(&self, node: usize) -> &[usize] {
+        &self.targets[self.out_arcs(node)]
+    }
+
+    pub fn source(&self, arc: usize) -> usize {
+        self.sources[arc]
+    }
+
+    pub fn target(&self, arc: usize) -> usize {
+        self.targets[arc]
+    }
+
+    pub fn weight(&self, arc: usize) -> W {
+        self.weights[arc]
+    }
+
+    pub fn capacity(&self, arc: usize) -> W {
+        self.capacities[arc]
+    }
+
+    /// Переводит значения, посчитанные по номерам вершин, обратно
+    /// к исходным идентификаторам. Пропущенные значения отбрасываются.
+    pub fn translate<T>(&self, values: Vec<Option<T>>) -> BTreeMap<N, T> {
+        values
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, value)| value.map(|value| (self.nodes[idx].clone(), value)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freeze_keeps_forward_and_reverse_arcs() {
+        let mut graph: Graph<char> = Graph::new_multigraph(true);
+
+        for node in 'A'..='C' {
+            graph.add_node(node);
+        }
+        graph.add_edge('A', 'B', 2, 5).unwrap();
+        graph.add_edge('A', 'B', 3, 7).unwrap();
+        graph.add_edge('C', 'B', 4, 1).unwrap();
+
+        let frozen = graph.freeze();
+        let a = frozen.index_of(&'A').unwrap();
+        let b = frozen.index_of(&'B').unwrap();
+        let c = frozen.index_of(&'C').unwrap();
+
+        assert_eq!(frozen.node_count(), 3);
+        assert_eq!(frozen.arc_count(), 3);
+        assert_eq!(frozen.successors(a), &[b, b]);
+        assert!(frozen.successors(b).is_empty());
+
+        let mut incoming: Vec<(usize, i32, i32)> = frozen
+            .in_arcs(b)
+            .iter()
+            .map(|&arc| (frozen.source(arc), frozen.weight(arc), frozen.capacity(arc)))
+            .collect();
+        incoming.sort();
+
+        let mut expected = vec![(a, 2, 5), (a, 3, 7), (c, 4, 1)];
+        expected.sort();
+        assert_eq!(incoming, expected);
+        assert_eq!(frozen.node(c), &'C');
+    }
+}
@@ -1,122 +1,345 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+    hash::Hash,
+};
 
 use bon::Builder;
 use derivative::Derivative;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
 use thiserror::Error;
 
+use crate::weight::Weight;
+
+/// Произвольные свойства вершины или ребра: метка, цвет, стоимость, время,
+/// координаты и любые другие значения JSON
+pub type Attributes = BTreeMap<String, Value>;
+
+/// Идентификатор вершины графа: число, строка, символ, кортеж и т.п.
+///
+/// Порядок на вершинах задаёт порядок обхода, вывода и сохранения графа,
+/// поэтому результаты не меняются от запуска к запуску.
+pub trait Node: Clone + Ord + Hash + Debug {}
+
+impl<T: Clone + Ord + Hash + Debug> Node for T {}
+
 // Сюда в будущем будут добавляться свойства рёбер/дуг
 #[derive(Derivative, Serialize, Deserialize, Builder)]
-#[derivative(Clone, Debug, PartialEq, Eq, Hash)]
+#[derivative(
+    Clone(bound = "N: Clone, W: Clone"),
+    Debug(bound = "N: Debug, W: Debug"),
+    PartialEq(bound = "N: PartialEq"),
+    Eq(bound = "N: Eq"),
+    PartialOrd(bound = "N: PartialOrd"),
+    Ord(bound = "N: Ord"),
+    Hash(bound = "N: Hash")
+)]
 #[builder(start_fn = value)]
-pub struct Edge {
+pub struct Edge<N, W: Weight = i32> {
     #[builder(start_fn)]
-    pub node: usize,
+    pub node: N,
 
-    #[derivative(PartialEq = "ignore", Hash = "ignore")]
-    #[builder(default = 1)]
+    #[derivative(
+        PartialEq = "ignore",
+        PartialOrd = "ignore",
+        Ord = "ignore",
+        Hash = "ignore"
+    )]
+    #[builder(default = W::one())]
     #[serde(default = "default_weight")]
-    pub weight: i32,
+    pub weight: W,
 
-    #[derivative(PartialEq = "ignore", Hash = "ignore")]
-    #[builder(default = 1)]
+    #[derivative(
+        PartialEq = "ignore",
+        PartialOrd = "ignore",
+        Ord = "ignore",
+        Hash = "ignore"
+    )]
+    #[builder(default = W::one())]
     #[serde(default = "default_capacity")]
-    pub capacity: i32,
+    pub capacity: W,
+
+    /// Идентификатор ребра/дуги в мультиграфе. Обе половины ребра имеют один
+    /// идентификатор. В простом графе всегда равен 0.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub id: usize,
+
+    /// Является ли запись половиной ребра смешанного графа
+    #[derivative(
+        PartialEq = "ignore",
+        PartialOrd = "ignore",
+        Ord = "ignore",
+        Hash = "ignore"
+    )]
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub rib: bool,
+
+    #[derivative(
+        PartialEq = "ignore",
+        PartialOrd = "ignore",
+        Ord = "ignore",
+        Hash = "ignore"
+    )]
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: Attributes,
 }
 
-fn default_weight() -> i32 {
-    1
+fn default_weight<W: Weight>() -> W {
+    W::one()
 }
 
-fn default_capacity() -> i32 {
-    1
+fn default_capacity<W: Weight>() -> W {
+    W::one()
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Graph {
-    pub edges: HashMap<usize, HashSet<Edge>>,
+fn is_zero(id: &usize) -> bool {
+    *id == 0
+}
+
+#[derive(Clone, Derivative, Serialize, Deserialize)]
+#[derivative(Debug(bound = "N: Debug, W: Debug"))]
+#[serde(
+    bound(
+        serialize = "N: Node + Serialize, W: Weight + Serialize",
+        deserialize = "N: Node + Deserialize<'de>, W: Weight + Deserialize<'de>"
+    ),
+    from = "GraphData<N, W>"
+)]
+pub struct Graph<N = usize, W: Weight = i32> {
+    /// Списки смежности. После прямого изменения этого поля нужно вызвать
+    /// [`Graph::reindex`], чтобы обновить индекс входящих дуг.
+    pub edges: BTreeMap<N, BTreeSet<Edge<N, W>>>,
     pub directed: bool,
+
+    /// Разрешены ли кратные (параллельные) дуги и рёбра
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multigraph: bool,
+
+    /// Смешанный граф: содержит одновременно дуги и рёбра. Ориентированный
+    /// флаг у такого графа всегда установлен, а рёбра помечаются полем
+    /// [`Edge::rib`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mixed: bool,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub node_attributes: BTreeMap<N, Attributes>,
+
+    /// Для каждой вершины: из каких вершин в неё входят дуги и сколько их
+    #[derivative(Debug = "ignore")]
+    #[serde(skip)]
+    incoming: BTreeMap<N, BTreeMap<N, usize>>,
+
+    /// Идентификатор для следующего ребра мультиграфа: больше всех занятых
+    #[derivative(Debug = "ignore")]
+    #[serde(skip)]
+    next_id: usize,
+}
+
+// Сериализованное представление графа без производных индексов
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "N: Node + Deserialize<'de>, W: Weight + Deserialize<'de>"))]
+struct GraphData<N, W: Weight> {
+    edges: BTreeMap<N, BTreeSet<Edge<N, W>>>,
+    directed: bool,
+
+    #[serde(default)]
+    multigraph: bool,
+
+    #[serde(default)]
+    mixed: bool,
+
+    #[serde(default)]
+    node_attributes: BTreeMap<N, Attributes>,
+}
+
+impl<N: Node, W: Weight> From<GraphData<N, W>> for Graph<N, W> {
+    fn from(data: GraphData<N, W>) -> Self {
+        let mut graph = Graph {
+            edges: data.edges,
+            directed: data.directed,
+            multigraph: data.multigraph,
+            mixed: data.mixed,
+            node_attributes: data.node_attributes,
+            incoming: BTreeMap::new(),
+            next_id: 1,
+        };
+        graph.reindex();
+        graph
+    }
 }
 
 #[derive(Clone, Debug, Error)]
-pub enum GraphRemoveNodeError {
-    #[error("Node not found")]
-    NodeNotFound,
+pub enum GraphRemoveNodeError<N: Debug> {
+    #[error("Node {0:?} not found")]
+    NodeNotFound(N),
 }
 
 #[derive(Clone, Debug, Error)]
-pub enum GraphAddEdgeError {
-    #[error("From node does not exist")]
-    FromNodeDoesNotExist,
+pub enum GraphAddEdgeError<N: Debug> {
+    #[error("From node {0:?} does not exist")]
+    FromNodeDoesNotExist(N),
 
-    #[error("To node does not exist")]
-    ToNodeDoesNotExist,
+    #[error("To node {0:?} does not exist")]
+    ToNodeDoesNotExist(N),
 
     #[error("Graph is undirected")]
     UndirectedGraph,
 
-    #[error("Edge already exists")]
-    EdgeAlreadyExists,
+    #[error("Edge {from:?} -> {to:?} already exists")]
+    EdgeAlreadyExists { from: N, to: N },
 }
 
 #[derive(Clone, Debug, Error)]
-pub enum GraphRemoveEdgeError {
-    #[error("From node does not exist")]
-    FromNodeDoesNotExist,
+pub enum GraphRemoveEdgeError<N: Debug> {
+    #[error("From node {0:?} does not exist")]
+    FromNodeDoesNotExist(N),
 
-    #[error("To node does not exist")]
-    ToNodeDoesNotExist,
+    #[error("To node {0:?} does not exist")]
+    ToNodeDoesNotExist(N),
 
     #[error("Graph is undirected")]
     UndirectedGraph,
 
-    #[error("Edge does not exist")]
-    EdgeDoesNotExist,
+    #[error("Edge {from:?} -> {to:?} does not exist")]
+    EdgeDoesNotExist { from: N, to: N, id: Option<usize> },
 }
 
 #[derive(Clone, Debug, Error)]
-pub enum GraphAddRibError {
-    #[error("From node does not exist")]
-    FirstNodeDoesNotExist,
+pub enum GraphAddRibError<N: Debug> {
+    #[error("First node {0:?} does not exist")]
+    FirstNodeDoesNotExist(N),
 
-    #[error("Second node does not exist")]
-    SecondNodeDoesNotExist,
+    #[error("Second node {0:?} does not exist")]
+    SecondNodeDoesNotExist(N),
 
-    #[error("Rib already exists")]
-    RibAlreadyExists,
+    #[error("Rib {first:?} - {second:?} already exists")]
+    RibAlreadyExists { first: N, second: N },
 }
 
 #[derive(Clone, Debug, Error)]
-pub enum GraphRemoveRibError {
-    #[error("First node does not exist")]
-    FirstNodeDoesNotExist,
+pub enum GraphRemoveRibError<N: Debug> {
+    #[error("First node {0:?} does not exist")]
+    FirstNodeDoesNotExist(N),
 
-    #[error("Second node does not exist")]
-    SecondNodeDoesNotExist,
+    #[error("Second node {0:?} does not exist")]
+    SecondNodeDoesNotExist(N),
 
-    #[error("Rib does not exist")]
-    RibDoesNotExist,
+    #[error("Rib {first:?} - {second:?} does not exist")]
+    RibDoesNotExist {
+        first: N,
+        second: N,
+        id: Option<usize>,
+    },
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum GraphAttributeError<N: Debug> {
+    #[error("Node {0:?} does not exist")]
+    NodeDoesNotExist(N),
+
+    #[error("Edge {from:?} -> {to:?} does not exist")]
+    EdgeDoesNotExist { from: N, to: N },
+
+    #[error("Edge has no attribute {0}")]
+    MissingAttribute(String),
+
+    #[error("Attribute {0} is not a valid weight")]
+    InvalidWeight(String),
+
+    #[error("Capacity {from:?} -> {to:?} must be finite and not negative")]
+    NegativeCapacity { from: N, to: N },
 }
 
 // TODO: Добавить total_weight
-impl Graph {
+impl<N: Node, W: Weight> Graph<N, W> {
     pub fn new(directed: bool) -> Self {
         Graph {
-            edges: HashMap::new(),
+            edges: BTreeMap::new(),
             directed,
+            multigraph: false,
+            mixed: false,
+            node_attributes: BTreeMap::new(),
+            incoming: BTreeMap::new(),
+            next_id: 1,
         }
     }
 
-    pub fn add_node(&mut self, value: usize) {
-        self.edges.entry(value).or_insert_with(HashSet::new);
+    /// Создаёт граф из готовых списков смежности
+    pub fn from_edges(directed: bool, edges: BTreeMap<N, BTreeSet<Edge<N, W>>>) -> Self {
+        let mut graph = Graph::new(directed);
+        graph.edges = edges;
+        graph.reindex();
+        graph
+    }
+
+    /// Перестраивает индекс входящих дуг и счётчик идентификаторов рёбер по
+    /// спискам смежности
+    pub fn reindex(&mut self) {
+        self.next_id = self
+            .edges
+            .values()
+            .flatten()
+            .map(|e| e.id + 1)
+            .max()
+            .unwrap_or(1);
+        self.incoming = self
+            .edges
+            .keys()
+            .map(|node| (node.clone(), BTreeMap::new()))
+            .collect();
+        let arcs: Vec<(N, N)> = self
+            .edges
+            .iter()
+            .flat_map(|(from, neighbours)| {
+                neighbours.iter().map(|e| (from.clone(), e.node.clone()))
+            })
+            .collect();
+        for (from, to) in arcs {
+            self.link(&from, &to);
+        }
+    }
+
+    pub fn new_multigraph(directed: bool) -> Self {
+        Graph {
+            multigraph: true,
+            ..Graph::new(directed)
+        }
     }
 
-    pub fn remove_node(&mut self, value: usize) -> Result<(), GraphRemoveNodeError> {
-        if !self.contains_node(value) {
-            return Err(GraphRemoveNodeError::NodeNotFound);
+    pub fn new_mixed() -> Self {
+        Graph {
+            mixed: true,
+            ..Graph::new(true)
         }
+    }
+
+    /// Удаляет все вершины и рёбра, сохраняя вид графа
+    pub fn clear(&mut self) {
+        self.edges.clear();
+        self.node_attributes.clear();
+        self.incoming.clear();
+        self.next_id = 1;
+    }
+
+    pub fn add_node(&mut self, value: N) {
+        self.incoming.entry(value.clone()).or_default();
+        self.edges.entry(value).or_default();
+    }
 
-        self.edges.remove(&value);
+    pub fn remove_node(&mut self, value: N) -> Result<(), GraphRemoveNodeError<N>> {
+        if !self.contains_node(&value) {
+            return Err(GraphRemoveNodeError::NodeNotFound(value));
+        }
+
+        for edge in self.edges.remove(&value).unwrap_or_default() {
+            self.unlink(&value, &edge.node, 1);
+        }
+        self.incoming.remove(&value);
+        self.node_attributes.remove(&value);
         for neighbours in self.edges.values_mut() {
             neighbours.retain(|e| e.node != value);
         }
@@ -124,120 +347,714 @@ impl Graph {
         Ok(())
     }
 
+    /// Добавляет дугу и возвращает её идентификатор
     pub fn add_edge(
         &mut self,
-        from: usize,
-        to: usize,
-        weight: i32,
-        capacity: i32,
-    ) -> Result<(), GraphAddEdgeError> {
+        from: N,
+        to: N,
+        weight: W,
+        capacity: W,
+    ) -> Result<usize, GraphAddEdgeError<N>> {
         if !self.directed {
             return Err(GraphAddEdgeError::UndirectedGraph);
         }
 
-        if !self.contains_node(from) {
-            return Err(GraphAddEdgeError::FromNodeDoesNotExist);
+        if !self.contains_node(&from) {
+            return Err(GraphAddEdgeError::FromNodeDoesNotExist(from));
         }
 
-        if !self.contains_node(to) {
-            return Err(GraphAddEdgeError::ToNodeDoesNotExist);
+        if !self.contains_node(&to) {
+            return Err(GraphAddEdgeError::ToNodeDoesNotExist(to));
         }
 
-        if self.contains_edge(from, to) {
-            return Err(GraphAddEdgeError::EdgeAlreadyExists);
+        if !self.multigraph && self.contains_edge(&from, &to) {
+            return Err(GraphAddEdgeError::EdgeAlreadyExists { from, to });
         }
 
-        self.edges
-            .entry(from)
-            .or_default()
-            .insert(Edge::value(to).weight(weight).capacity(capacity).build());
+        let id = self.next_edge_id();
+        self.insert_edge(
+            from,
+            Edge::value(to)
+                .weight(weight)
+                .capacity(capacity)
+                .id(id)
+                .build(),
+        );
 
-        Ok(())
+        Ok(id)
     }
 
-    pub fn remove_edge(&mut self, from: usize, to: usize) -> Result<(), GraphRemoveEdgeError> {
-        if !self.contains_node(from) {
-            return Err(GraphRemoveEdgeError::FromNodeDoesNotExist);
+    /// Удаляет все дуги из `from` в `to`, в том числе параллельные
+    pub fn remove_edge(&mut self, from: N, to: N) -> Result<(), GraphRemoveEdgeError<N>> {
+        if !self.contains_node(&from) {
+            return Err(GraphRemoveEdgeError::FromNodeDoesNotExist(from));
+        }
+
+        if !self.contains_node(&to) {
+            return Err(GraphRemoveEdgeError::ToNodeDoesNotExist(to));
+        }
+
+        if !self.edges[&from].iter().any(|e| e.node == to && !e.rib) {
+            return Err(GraphRemoveEdgeError::EdgeDoesNotExist { from, to, id: None });
         }
 
-        if !self.contains_node(to) {
-            return Err(GraphRemoveEdgeError::ToNodeDoesNotExist);
+        self.remove_edges(&from, |e| e.node == to && !e.rib);
+
+        Ok(())
+    }
+
+    /// Удаляет одну конкретную дугу из `from` в `to`
+    pub fn remove_edge_with_id(
+        &mut self,
+        from: N,
+        to: N,
+        id: usize,
+    ) -> Result<(), GraphRemoveEdgeError<N>> {
+        if !self.contains_node(&from) {
+            return Err(GraphRemoveEdgeError::FromNodeDoesNotExist(from));
         }
 
-        if !self.contains_edge(from, to) {
-            return Err(GraphRemoveEdgeError::EdgeDoesNotExist);
+        if !self.contains_node(&to) {
+            return Err(GraphRemoveEdgeError::ToNodeDoesNotExist(to));
         }
 
-        self.edges.entry(from).or_default().retain(|e| e.node != to);
+        let edge = Edge::value(to.clone()).id(id).build();
+        if self.edges[&from].get(&edge).is_none_or(|e| e.rib) {
+            return Err(GraphRemoveEdgeError::EdgeDoesNotExist {
+                from,
+                to,
+                id: Some(id),
+            });
+        }
+        self.remove_edges(&from, |e| e.node == to && e.id == id);
 
         Ok(())
     }
 
+    /// Добавляет ребро и возвращает его идентификатор
     pub fn add_rib(
         &mut self,
-        first: usize,
-        second: usize,
-        weight: i32,
-        capacity: i32,
-    ) -> Result<(), GraphAddRibError> {
-        if !self.contains_node(first) {
-            return Err(GraphAddRibError::FirstNodeDoesNotExist);
+        first: N,
+        second: N,
+        weight: W,
+        capacity: W,
+    ) -> Result<usize, GraphAddRibError<N>> {
+        if !self.contains_node(&first) {
+            return Err(GraphAddRibError::FirstNodeDoesNotExist(first));
         }
 
-        if !self.contains_node(second) {
-            return Err(GraphAddRibError::SecondNodeDoesNotExist);
+        if !self.contains_node(&second) {
+            return Err(GraphAddRibError::SecondNodeDoesNotExist(second));
         }
 
-        if self.contains_edge(first, second) || self.contains_edge(second, first) {
-            return Err(GraphAddRibError::RibAlreadyExists);
+        if !self.multigraph
+            && (self.contains_edge(&first, &second) || self.contains_edge(&second, &first))
+        {
+            return Err(GraphAddRibError::RibAlreadyExists { first, second });
         }
 
-        self.edges.entry(first).or_default().insert(
-            Edge::value(second)
+        // В ориентированном (не смешанном) графе ребро заменяется парой дуг
+        let id = self.next_edge_id();
+        self.insert_edge(
+            first.clone(),
+            Edge::value(second.clone())
                 .weight(weight)
                 .capacity(capacity)
+                .id(id)
+                .rib(self.mixed)
                 .build(),
         );
 
-        self.edges
-            .entry(second)
-            .or_default()
-            .insert(Edge::value(first).weight(weight).capacity(capacity).build());
+        self.insert_edge(
+            second,
+            Edge::value(first)
+                .weight(weight)
+                .capacity(capacity)
+                .id(id)
+                .rib(self.mixed)
+                .build(),
+        );
+
+        Ok(id)
+    }
+
+    /// Удаляет все рёбра между `first` и `second`, в том числе параллельные
+    pub fn remove_rib(&mut self, first: N, second: N) -> Result<(), GraphRemoveRibError<N>> {
+        if !self.contains_node(&first) {
+            return Err(GraphRemoveRibError::FirstNodeDoesNotExist(first));
+        }
+
+        if !self.contains_node(&second) {
+            return Err(GraphRemoveRibError::SecondNodeDoesNotExist(second));
+        }
+
+        if !self.contains_rib_half(&first, &second) || !self.contains_rib_half(&second, &first) {
+            return Err(GraphRemoveRibError::RibDoesNotExist {
+                first,
+                second,
+                id: None,
+            });
+        }
+
+        let mixed = self.mixed;
+        self.remove_edges(&first, |e| e.node == second && e.rib == mixed);
+        self.remove_edges(&second, |e| e.node == first && e.rib == mixed);
 
         Ok(())
     }
 
-    pub fn remove_rib(&mut self, first: usize, second: usize) -> Result<(), GraphRemoveRibError> {
-        if !self.contains_node(first) {
-            return Err(GraphRemoveRibError::FirstNodeDoesNotExist);
+    /// Удаляет одно конкретное ребро между `first` и `second`
+    pub fn remove_rib_with_id(
+        &mut self,
+        first: N,
+        second: N,
+        id: usize,
+    ) -> Result<(), GraphRemoveRibError<N>> {
+        if !self.contains_node(&first) {
+            return Err(GraphRemoveRibError::FirstNodeDoesNotExist(first));
         }
 
-        if !self.contains_node(second) {
-            return Err(GraphRemoveRibError::SecondNodeDoesNotExist);
+        if !self.contains_node(&second) {
+            return Err(GraphRemoveRibError::SecondNodeDoesNotExist(second));
         }
 
-        if !self.contains_edge(first, second) || !self.contains_edge(second, first) {
-            return Err(GraphRemoveRibError::RibDoesNotExist);
+        let forward = Edge::value(second.clone()).id(id).build();
+        let backward = Edge::value(first.clone()).id(id).build();
+        let is_rib_half = |node: &N, edge: &Edge<N, W>| {
+            self.edges[node]
+                .get(edge)
+                .is_some_and(|e| e.rib == self.mixed)
+        };
+        if !is_rib_half(&first, &forward) || !is_rib_half(&second, &backward) {
+            return Err(GraphRemoveRibError::RibDoesNotExist {
+                first,
+                second,
+                id: Some(id),
+            });
         }
 
+        self.remove_edges(&first, |e| e.node == second && e.id == id);
+        self.remove_edges(&second, |e| e.node == first && e.id == id);
+
+        Ok(())
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.edges.contains_key(node)
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        self.edges[from].iter().any(|e| e.node == *to)
+    }
+
+    /// Является ли запись в списке смежности половиной ребра, а не дугой
+    pub fn is_rib(&self, edge: &Edge<N, W>) -> bool {
+        !self.directed || edge.rib
+    }
+
+    // В смешанном графе половины рёбер помечены, в остальных графах ребром
+    // считается любая пара встречных записей
+    fn contains_rib_half(&self, from: &N, to: &N) -> bool {
+        self.edges[from]
+            .iter()
+            .any(|e| e.node == *to && e.rib == self.mixed)
+    }
+
+    /// Идентификаторы всех дуг из `from` в `to`
+    pub fn edge_ids(&self, from: &N, to: &N) -> Vec<usize> {
         self.edges
-            .entry(first)
+            .get(from)
+            .into_iter()
+            .flatten()
+            .filter(|e| e.node == *to)
+            .map(|e| e.id)
+            .collect()
+    }
+
+    pub fn node_attribute(&self, node: &N, key: &str) -> Option<&Value> {
+        self.node_attributes.get(node)?.get(key)
+    }
+
+    pub fn set_node_attribute(
+        &mut self,
+        node: N,
+        key: impl Into<String>,
+        value: Value,
+    ) -> Result<(), GraphAttributeError<N>> {
+        if !self.contains_node(&node) {
+            return Err(GraphAttributeError::NodeDoesNotExist(node));
+        }
+
+        self.node_attributes
+            .entry(node)
             .or_default()
-            .retain(|e| e.node != second);
+            .insert(key.into(), value);
 
+        Ok(())
+    }
+
+    /// Значение свойства первой из дуг `from` -> `to`
+    pub fn edge_attribute(&self, from: &N, to: &N, key: &str) -> Option<&Value> {
         self.edges
-            .entry(second)
-            .or_default()
-            .retain(|e| e.node != first);
+            .get(from)?
+            .iter()
+            .find(|e| e.node == *to)?
+            .attributes
+            .get(key)
+    }
+
+    /// Задаёт свойство всем дугам `from` -> `to`. У рёбер свойство
+    /// обновляется в обеих половинах.
+    pub fn set_edge_attribute(
+        &mut self,
+        from: N,
+        to: N,
+        key: impl Into<String>,
+        value: Value,
+    ) -> Result<(), GraphAttributeError<N>> {
+        let key = key.into();
+        self.update_edges(from, to, |edge| {
+            edge.attributes.insert(key.clone(), value.clone());
+        })
+    }
+
+    /// Удаляет свойство у всех дуг `from` -> `to` и возвращает, было ли оно
+    pub fn remove_edge_attribute(
+        &mut self,
+        from: N,
+        to: N,
+        key: &str,
+    ) -> Result<bool, GraphAttributeError<N>> {
+        let mut removed = false;
+        self.update_edges(from, to, |edge| {
+            removed |= edge.attributes.remove(key).is_some();
+        })?;
+
+        Ok(removed)
+    }
+
+    /// Изменяет набор свойств всех дуг `from` -> `to` произвольной функцией.
+    /// У рёбер обе половины получают одинаковый результат.
+    pub fn update_edge_attributes(
+        &mut self,
+        from: N,
+        to: N,
+        mut update: impl FnMut(&mut Attributes),
+    ) -> Result<(), GraphAttributeError<N>> {
+        self.update_edges(from, to, |edge| update(&mut edge.attributes))
+    }
+
+    /// Задаёт вес всем дугам `from` -> `to` (обеим половинам ребра)
+    pub fn set_weight(&mut self, from: N, to: N, weight: W) -> Result<(), GraphAttributeError<N>> {
+        self.update_edges(from, to, |edge| edge.weight = weight)
+    }
+
+    /// Задаёт пропускную способность всем дугам `from` -> `to`. Отрицательная,
+    /// бесконечная или `NaN` пропускная способность не принимается.
+    pub fn set_capacity(
+        &mut self,
+        from: N,
+        to: N,
+        capacity: W,
+    ) -> Result<(), GraphAttributeError<N>> {
+        if !capacity.is_finite() || capacity < W::zero() {
+            return Err(GraphAttributeError::NegativeCapacity { from, to });
+        }
+
+        self.update_edges(from, to, |edge| edge.capacity = capacity)
+    }
+
+    /// Копия графа, в которой веса рёбер взяты из свойства `key`
+    pub fn weighted_by(&self, key: &str) -> Result<Self, GraphAttributeError<N>>
+    where
+        W: DeserializeOwned,
+    {
+        self.with_edge_values_from(key, |edge, value| edge.weight = value)
+    }
+
+    /// Копия графа, в которой пропускные способности взяты из свойства `key`
+    pub fn capacity_by(&self, key: &str) -> Result<Self, GraphAttributeError<N>>
+    where
+        W: DeserializeOwned,
+    {
+        self.with_edge_values_from(key, |edge, value| edge.capacity = value)
+    }
+
+    fn with_edge_values_from(
+        &self,
+        key: &str,
+        set: impl Fn(&mut Edge<N, W>, W),
+    ) -> Result<Self, GraphAttributeError<N>>
+    where
+        W: DeserializeOwned,
+    {
+        let mut graph = self.clone();
+        for neighbours in graph.edges.values_mut() {
+            *neighbours = std::mem::take(neighbours)
+                .into_iter()
+                .map(|mut edge| {
+                    let value = edge
+                        .attributes
+                        .get(key)
+                        .ok_or_else(|| GraphAttributeError::MissingAttribute(key.to_string()))?;
+                    let value = serde_json::from_value(value.clone())
+                        .map_err(|_| GraphAttributeError::InvalidWeight(key.to_string()))?;
+                    set(&mut edge, value);
+                    Ok(edge)
+                })
+                .collect::<Result<_, _>>()?;
+        }
+
+        Ok(graph)
+    }
+
+    // Изменяет все дуги `from` -> `to`. Для рёбер изменяется и встречная
+    // половина с тем же идентификатором.
+    fn update_edges(
+        &mut self,
+        from: N,
+        to: N,
+        mut update: impl FnMut(&mut Edge<N, W>),
+    ) -> Result<(), GraphAttributeError<N>> {
+        for node in [&from, &to] {
+            if !self.contains_node(node) {
+                return Err(GraphAttributeError::NodeDoesNotExist(node.clone()));
+            }
+        }
+
+        let targets: Vec<Edge<N, W>> = self.edges[&from]
+            .iter()
+            .filter(|e| e.node == to)
+            .cloned()
+            .collect();
+        if targets.is_empty() {
+            return Err(GraphAttributeError::EdgeDoesNotExist { from, to });
+        }
+
+        for mut edge in targets {
+            let is_rib = self.is_rib(&edge);
+            let neighbours = self.edges.entry(from.clone()).or_default();
+            neighbours.remove(&edge);
+            update(&mut edge);
+            let id = edge.id;
+            neighbours.insert(edge);
+
+            if is_rib && from != to {
+                let half = Edge::value(from.clone()).id(id).build();
+                let neighbours = self.edges.entry(to.clone()).or_default();
+                if let Some(mut half) = neighbours.take(&half) {
+                    update(&mut half);
+                    neighbours.insert(half);
+                }
+            }
+        }
 
         Ok(())
     }
 
-    pub fn contains_node(&self, node: usize) -> bool {
-        self.edges.contains_key(&node)
+    /// Все вершины графа по возрастанию
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+
+    /// Все записи списков смежности в виде `(начало, конец, ребро)`. Ребро
+    /// неориентированного или смешанного графа встречается дважды, по
+    /// одному разу в каждом направлении.
+    pub fn arcs(&self) -> impl Iterator<Item = (&N, &N, &Edge<N, W>)> {
+        self.edges
+            .iter()
+            .flat_map(|(from, edges)| edges.iter().map(move |edge| (from, &edge.node, edge)))
+    }
+
+    /// Каждое ребро (но не дуга) ровно один раз, от меньшей вершины к большей
+    pub fn ribs(&self) -> impl Iterator<Item = (&N, &N, &Edge<N, W>)> {
+        self.arcs()
+            .filter(|(from, to, edge)| self.is_rib(edge) && from <= to)
+    }
+
+    /// Записи списка смежности вершины: исходящие дуги и половины рёбер
+    pub fn out_edges<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a Edge<N, W>> + 'a {
+        self.edges.get(node).into_iter().flatten()
+    }
+
+    /// Концы всех исходящих из вершины дуг. Вершина повторяется столько раз,
+    /// сколько параллельных дуг в неё ведёт.
+    pub fn successors<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a N> + 'a {
+        self.out_edges(node).map(|edge| &edge.node)
+    }
+
+    /// Все смежные вершины без повторов: и концы исходящих, и начала
+    /// входящих дуг
+    pub fn neighbours<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a N> + 'a {
+        self.successors(node)
+            .chain(self.predecessors(node))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+    }
+
+    /// Количество дуг (и половин рёбер), исходящих из вершины
+    pub fn out_degree(&self, node: &N) -> usize {
+        self.edges.get(node).map_or(0, BTreeSet::len)
+    }
+
+    /// Степень вершины: число инцидентных ей дуг и рёбер. В
+    /// неориентированном графе совпадает с [`Graph::out_degree`], в
+    /// ориентированном равна сумме полустепеней.
+    pub fn degree(&self, node: &N) -> usize {
+        // Каждая половина ребра из вершины имеет встречную половину в неё,
+        // поэтому рёбра вычитаются, чтобы не считать их дважды
+        let ribs = self.out_edges(node).filter(|e| self.is_rib(e)).count();
+        self.out_degree(node) + self.in_degree(node) - ribs
+    }
+
+    /// Количество дуг (и половин рёбер), входящих в вершину
+    pub fn in_degree(&self, node: &N) -> usize {
+        self.incoming
+            .get(node)
+            .map_or(0, |sources| sources.values().sum())
+    }
+
+    /// Начала всех входящих в вершину дуг. Вершина повторяется столько раз,
+    /// сколько параллельных дуг из неё выходит.
+    pub fn predecessors<'a>(&'a self, node: &N) -> impl Iterator<Item = &'a N> + 'a {
+        self.incoming
+            .get(node)
+            .into_iter()
+            .flatten()
+            .flat_map(|(source, &count)| std::iter::repeat_n(source, count))
+    }
+
+    fn insert_edge(&mut self, from: N, edge: Edge<N, W>) {
+        let to = edge.node.clone();
+        self.next_id = self.next_id.max(edge.id + 1);
+        if self.edges.entry(from.clone()).or_default().insert(edge) {
+            self.link(&from, &to);
+        }
+    }
+
+    fn remove_edges(&mut self, from: &N, mut remove: impl FnMut(&Edge<N, W>) -> bool) {
+        let Some(neighbours) = self.edges.get_mut(from) else {
+            return;
+        };
+
+        let mut removed = Vec::new();
+        neighbours.retain(|e| {
+            let keep = !remove(e);
+            if !keep {
+                removed.push(e.node.clone());
+            }
+            keep
+        });
+
+        for to in removed {
+            self.unlink(from, &to, 1);
+        }
+    }
+
+    fn link(&mut self, from: &N, to: &N) {
+        *self
+            .incoming
+            .entry(to.clone())
+            .or_default()
+            .entry(from.clone())
+            .or_default() += 1;
     }
 
-    pub fn contains_edge(&self, from: usize, to: usize) -> bool {
-        self.edges[&from].contains(&Edge::value(to).build())
+    fn unlink(&mut self, from: &N, to: &N, count: usize) {
+        let Some(sources) = self.incoming.get_mut(to) else {
+            return;
+        };
+
+        if let Some(current) = sources.get_mut(from) {
+            *current = current.saturating_sub(count);
+            if *current == 0 {
+                sources.remove(from);
+            }
+        }
+    }
+
+    pub(crate) fn next_edge_id(&self) -> usize {
+        if self.multigraph { self.next_id } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::weight::WeightPolicy;
+
+    use super::*;
+
+    #[test]
+    fn test_rib_attributes_stay_in_sync() {
+        let mut graph: Graph<usize, f64> = Graph::new(false);
+
+        graph.add_node(1);
+        graph.add_node(2);
+        graph.add_rib(1, 2, 1.0, 1.0).unwrap();
+
+        graph.set_edge_attribute(2, 1, "km", json!(2.5)).unwrap();
+
+        assert_eq!(graph.edge_attribute(&1, &2, "km"), Some(&json!(2.5)));
+        assert_eq!(graph.edge_attribute(&2, &1, "km"), Some(&json!(2.5)));
+
+        let weighted = graph.weighted_by("km").unwrap();
+        assert!(weighted.edges.values().flatten().all(|e| e.weight == 2.5));
+    }
+
+    #[test]
+    fn test_attributes_round_trip_through_json() {
+        let mut graph: Graph<String> = Graph::new(true);
+
+        graph.add_node("A".to_string());
+        graph.add_node("B".to_string());
+        graph
+            .add_edge("A".to_string(), "B".to_string(), 3, 1)
+            .unwrap();
+        graph
+            .set_node_attribute("A".to_string(), "xy", json!([1.5, -2]))
+            .unwrap();
+        graph
+            .set_edge_attribute("A".to_string(), "B".to_string(), "colour", json!("red"))
+            .unwrap();
+
+        let loaded: Graph<String> =
+            serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+
+        assert_eq!(
+            loaded.node_attribute(&"A".to_string(), "xy"),
+            Some(&json!([1.5, -2]))
+        );
+        assert_eq!(
+            loaded.edge_attribute(&"A".to_string(), &"B".to_string(), "colour"),
+            Some(&json!("red"))
+        );
+    }
+
+    #[test]
+    fn test_incoming_index_follows_mutations() {
+        let mut graph: Graph<usize> = Graph::new_multigraph(true);
+
+        for i in 1..=3 {
+            graph.add_node(i);
+        }
+        let id = graph.add_edge(1, 3, 1, 1).unwrap();
+        graph.add_edge(1, 3, 1, 1).unwrap();
+        graph.add_edge(2, 3, 1, 1).unwrap();
+
+        assert_eq!(graph.in_degree(&3), 3);
+
+        graph.remove_edge_with_id(1, 3, id).unwrap();
+        assert_eq!(graph.in_degree(&3), 2);
+
+        graph.remove_node(2).unwrap();
+        assert_eq!(graph.predecessors(&3).collect::<Vec<_>>(), vec![&1]);
+
+        let loaded: Graph<usize> =
+            serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+        assert_eq!(loaded.in_degree(&3), 1);
+        assert_eq!(loaded.in_degree(&1), 0);
+    }
+
+    #[test]
+    fn test_edge_ids_after_load_and_removal() {
+        let mut graph: Graph<usize> = serde_json::from_str(
+            r#"{"edges": {"1": [{"node": 2, "id": 5}], "2": []}, "directed": true, "multigraph": true}"#,
+        )
+        .unwrap();
+        assert_eq!(graph.add_edge(1, 2, 1, 1).unwrap(), 6);
+
+        // Освободившийся идентификатор не выдаётся повторно
+        graph.remove_edge_with_id(1, 2, 6).unwrap();
+        assert_eq!(graph.add_rib(2, 1, 1, 1).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_serialization_is_ordered() {
+        let mut graph: Graph<usize> = Graph::new(true);
+
+        for i in [3, 1, 2] {
+            graph.add_node(i);
+        }
+        graph.add_edge(3, 2, 1, 1).unwrap();
+        graph.add_edge(3, 1, 1, 1).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&graph).unwrap(),
+            r#"{"edges":{"1":[],"2":[],"3":[{"node":1,"weight":1,"capacity":1},{"node":2,"weight":1,"capacity":1}]},"directed":true}"#
+        );
+    }
+
+    #[test]
+    fn test_set_weight_keeps_rib_halves_consistent() {
+        let mut graph: Graph<char> = Graph::new_mixed();
+
+        for node in 'A'..='C' {
+            graph.add_node(node);
+        }
+        graph.add_rib('A', 'B', 1, 1).unwrap();
+        graph.add_edge('B', 'C', 1, 1).unwrap();
+
+        graph.set_weight('B', 'A', 5).unwrap();
+        graph.set_capacity('B', 'C', 3).unwrap();
+
+        assert!(
+            graph
+                .edges
+                .values()
+                .flatten()
+                .filter(|e| e.rib)
+                .all(|e| e.weight == 5)
+        );
+        assert_eq!(
+            graph.edges[&'B']
+                .iter()
+                .find(|e| e.node == 'C')
+                .unwrap()
+                .capacity,
+            3
+        );
+        assert!(matches!(
+            graph.set_capacity('B', 'C', -1),
+            Err(GraphAttributeError::NegativeCapacity { from: 'B', to: 'C' })
+        ));
+        assert!(matches!(
+            graph.set_weight('C', 'A', 2),
+            Err(GraphAttributeError::EdgeDoesNotExist { from: 'C', to: 'A' })
+        ));
+    }
+
+    #[test]
+    fn test_queries_on_mixed_graph() {
+        let mut graph: Graph<char> = Graph::new_mixed();
+
+        for node in 'A'..='D' {
+            graph.add_node(node);
+        }
+        graph.add_rib('A', 'B', 1, 1).unwrap();
+        graph.add_edge('B', 'C', 1, 1).unwrap();
+        graph.add_edge('D', 'B', 1, 1).unwrap();
+
+        assert_eq!(graph.nodes().count(), 4);
+        assert_eq!(graph.arcs().count(), 4);
+        assert_eq!(
+            graph
+                .ribs()
+                .map(|(from, to, _)| (*from, *to))
+                .collect::<Vec<_>>(),
+            vec![('A', 'B')]
+        );
+        assert_eq!(graph.successors(&'B').collect::<Vec<_>>(), vec![&'A', &'C']);
+        assert_eq!(
+            graph.neighbours(&'B').collect::<Vec<_>>(),
+            vec![&'A', &'C', &'D']
+        );
+        assert_eq!(graph.out_degree(&'B'), 2);
+        assert_eq!(graph.degree(&'B'), 3);
+
+        let undirected = graph.to_undirected(WeightPolicy::Min);
+        assert_eq!(undirected.ribs().count(), 3);
+        assert_eq!(undirected.degree(&'B'), 3);
     }
 }
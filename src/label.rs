@@ -0,0 +1,116 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
+
+/// Метка вершины, которую можно ввести с клавиатуры или прочитать из файла.
+///
+/// Числовые метки хранятся как числа, поэтому `"1"` в ключе JSON-объекта
+/// и `1` в поле `node` ребра обозначают одну и ту же вершину.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Label {
+    Number(i64),
+    Text(String),
+}
+
+impl From<&str> for Label {
+    fn from(value: &str) -> Self {
+        match value.parse() {
+            Ok(number) => Label::Number(number),
+            Err(_) => Label::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<i64> for Label {
+    fn from(value: i64) -> Self {
+        Label::Number(value)
+    }
+}
+
+impl FromStr for Label {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Label::from(s))
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Label::Number(number) => write!(f, "{number}"),
+            Label::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl fmt::Debug for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Label::Number(number) => write!(f, "{number:?}"),
+            Label::Text(text) => write!(f, "{text:?}"),
+        }
+    }
+}
+
+impl Serialize for Label {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Label::Number(number) => serializer.serialize_i64(*number),
+            Label::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(LabelVisitor)
+    }
+}
+
+struct LabelVisitor;
+
+impl Visitor<'_> for LabelVisitor {
+    type Value = Label;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number or a string")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Label::Number(v))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        i64::try_from(v)
+            .map(Label::Number)
+            .map_err(|_| E::custom("node number is too large"))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Label::from(v))
+    }
+
+    fn visit_char<E: serde::de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(Label::from(v.encode_utf8(&mut [0; 4]) as &str))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn test_label_json_keys_match_values() {
+        let map: BTreeMap<Label, Vec<Label>> =
+            serde_json::from_str(r#"{"1": [2, "A"], "A": ["1"]}"#).unwrap();
+
+        assert_eq!(
+            map[&Label::Number(1)],
+            vec![Label::Number(2), Label::Text("A".to_string())]
+        );
+        assert_eq!(map[&Label::Text("A".to_string())], vec![Label::Number(1)]);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod algorithms;
+pub mod batch;
+pub mod canonical;
+pub mod convert;
+pub mod error;
+pub mod files;
+pub mod frozen;
+pub mod graph;
+pub mod label;
+pub mod validate;
+pub mod weight;
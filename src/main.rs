@@ -1,10 +1,8 @@
 use crate::cli::{cmd_loop::cmd_loop, init_graph::init_graph};
 
-pub mod cli;
-pub mod files;
-pub mod graph;
-pub mod tasks;
+mod cli;
 
 fn main() {
-    cmd_loop(init_graph());
+    let (graph, start) = init_graph();
+    cmd_loop(graph, start);
 }
@@ -1,10 +0,0 @@
-pub mod task10;
-pub mod task11;
-pub mod task2;
-pub mod task3;
-pub mod task4;
-pub mod task5;
-pub mod task6;
-pub mod task7;
-pub mod task8;
-pub mod task9;
@@ -0,0 +1,273 @@
+use std::fmt::{self, Debug, Display};
+
+use thiserror::Error;
+
+use crate::{
+    graph::{Edge, Graph, Node},
+    weight::Weight,
+};
+
+/// Допустимы ли петли (дуги и рёбра из вершины в неё же)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelfLoops {
+    #[default]
+    Allow,
+    Forbid,
+}
+
+/// Структурная ошибка графа со ссылкой на вершины и ребро
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum Problem<N: Debug, W: Debug> {
+    #[error("Arc {from:?} -> {to:?} (id {id}) points to a missing node")]
+    DanglingTarget { from: N, to: N, id: usize },
+
+    #[error("Rib {from:?} - {to:?} (id {id}) has no opposite half")]
+    AsymmetricRib { from: N, to: N, id: usize },
+
+    #[error("Self-loop at {node:?} (id {id})")]
+    SelfLoop { node: N, id: usize },
+
+    #[error("Arc {from:?} -> {to:?} (id {id}) has negative or non-finite capacity {capacity:?}")]
+    NegativeCapacity {
+        from: N,
+        to: N,
+        id: usize,
+        capacity: W,
+    },
+}
+
+/// Все найденные в графе проблемы
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError<N: Debug, W: Debug>(pub Vec<Problem<N, W>>);
+
+impl<N: Debug, W: Debug> Display for ValidationError<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Graph has {} structural problem(s)", self.0.len())?;
+        for problem in &self.0 {
+            write!(f, "\n  {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Debug, W: Debug> std::error::Error for ValidationError<N, W> {}
+
+/// Проверяет структуру графа и возвращает все найденные проблемы
+pub fn validate<N: Node, W: Weight>(
+    graph: &Graph<N, W>,
+    self_loops: SelfLoops,
+) -> Vec<Problem<N, W>> {
+    let mut problems = Vec::new();
+
+    for (from, edges) in &graph.edges {
+        for edge in edges {
+            let to = &edge.node;
+
+            if !graph.contains_node(to) {
+                problems.push(Problem::DanglingTarget {
+                    from: from.clone(),
+                    to: to.clone(),
+                    id: edge.id,
+                });
+            } else if graph.is_rib(edge) && opposite_half(graph, from, edge).is_none() {
+                problems.push(Problem::AsymmetricRib {
+                    from: from.clone(),
+                    to: to.clone(),
+                    id: edge.id,
+                });
+            }
+
+            if self_loops == SelfLoops::Forbid && from == to {
+                problems.push(Problem::SelfLoop {
+                    node: from.clone(),
+                    id: edge.id,
+                });
+            }
+
+            if !edge.capacity.is_finite() || edge.capacity < W::zero() {
+                problems.push(Problem::NegativeCapacity {
+                    from: from.clone(),
+                    to: to.clone(),
+                    id: edge.id,
+                    capacity: edge.capacity,
+                });
+            }
+        }
+    }
+
+    problems
+}
+
+/// Исправляет найденные проблемы и возвращает список исправленного.
+///
+/// Недостающие вершины добавляются, у рёбер достраивается обратная половина,
+/// запрещённые петли удаляются, отрицательная пропускная способность
+/// заменяется нулём.
+pub fn repair<N: Node, W: Weight>(
+    graph: &mut Graph<N, W>,
+    self_loops: SelfLoops,
+) -> Vec<Problem<N, W>> {
+    let problems = validate(graph, self_loops);
+
+    for problem in &problems {
+        match problem {
+            Problem::DanglingTarget { to, .. } => {
+                graph.add_node(to.clone());
+            }
+            Problem::SelfLoop { node, id } => {
+                if let Some(edges) = graph.edges.get_mut(node) {
+                    edges.retain(|e| e.node != *node || e.id != *id);
+                }
+            }
+            Problem::NegativeCapacity { from, to, id, .. } => {
+                let edges = graph.edges.get_mut(from).unwrap();
+                *edges = std::mem::take(edges)
+                    .into_iter()
+                    .map(|mut e| {
+                        if e.node == *to && e.id == *id {
+                            e.capacity = W::zero();
+                        }
+                        e
+                    })
+                    .collect();
+            }
+            Problem::AsymmetricRib { .. } => {}
+        }
+    }
+
+    // Недостающие половины рёбер достраиваются после добавления вершин,
+    // поэтому ребро к висячей вершине тоже становится симметричным
+    let missing: Vec<(N, Edge<N, W>)> = graph
+        .arcs()
+        .filter(|(from, _, edge)| graph.is_rib(edge) && opposite_half(graph, from, edge).is_none())
+        .map(|(from, _, edge)| {
+            let mut half = edge.clone();
+            half.node = from.clone();
+            (edge.node.clone(), half)
+        })
+        .collect();
+    let repaired_ribs = missing.iter().map(|(to, half)| Problem::AsymmetricRib {
+        from: half.node.clone(),
+        to: to.clone(),
+        id: half.id,
+    });
+    let problems = problems
+        .iter()
+        .filter(|p| !matches!(p, Problem::AsymmetricRib { .. }))
+        .cloned()
+        .chain(repaired_ribs)
+        .collect();
+
+    for (to, half) in missing {
+        graph.edges.entry(to).or_default().insert(half);
+    }
+
+    graph.reindex();
+    problems
+}
+
+fn opposite_half<'a, N: Node, W: Weight>(
+    graph: &'a Graph<N, W>,
+    from: &N,
+    edge: &Edge<N, W>,
+) -> Option<&'a Edge<N, W>> {
+    graph
+        .edges
+        .get(&edge.node)?
+        .iter()
+        .find(|e| e.node == *from && e.id == edge.id && graph.is_rib(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::{btreemap, btreeset};
+
+    use super::*;
+    use crate::graph::GraphAttributeError;
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let graph: Graph<usize> = Graph::from_edges(
+            false,
+            btreemap! {
+                1 => btreeset! { Edge::value(2).build(), Edge::value(7).build() },
+                2 => btreeset! { Edge::value(3).capacity(-4).build() },
+                3 => btreeset! { Edge::value(2).capacity(-4).build(), Edge::value(3).build() },
+            },
+        );
+
+        assert_eq!(
+            validate(&graph, SelfLoops::Forbid),
+            vec![
+                Problem::AsymmetricRib {
+                    from: 1,
+                    to: 2,
+                    id: 0
+                },
+                Problem::DanglingTarget {
+                    from: 1,
+                    to: 7,
+                    id: 0
+                },
+                Problem::NegativeCapacity {
+                    from: 2,
+                    to: 3,
+                    id: 0,
+                    capacity: -4
+                },
+                Problem::NegativeCapacity {
+                    from: 3,
+                    to: 2,
+                    id: 0,
+                    capacity: -4
+                },
+                Problem::SelfLoop { node: 3, id: 0 },
+            ]
+        );
+        assert_eq!(validate(&graph, SelfLoops::Allow).len(), 4);
+    }
+
+    #[test]
+    fn test_validate_non_finite_capacity() {
+        let mut graph: Graph<usize, f64> = Graph::from_edges(
+            true,
+            btreemap! {
+                1 => btreeset! { Edge::value(2).capacity(f64::NAN).build() },
+                2 => btreeset! { Edge::value(1).capacity(f64::INFINITY).build() },
+            },
+        );
+
+        let problems = validate(&graph, SelfLoops::Allow);
+        assert_eq!(problems.len(), 2);
+        assert!(
+            problems
+                .iter()
+                .all(|problem| matches!(problem, Problem::NegativeCapacity { .. }))
+        );
+
+        assert!(matches!(
+            graph.set_capacity(1, 2, f64::NAN),
+            Err(GraphAttributeError::NegativeCapacity { from: 1, to: 2 })
+        ));
+        repair(&mut graph, SelfLoops::Allow);
+        assert!(validate(&graph, SelfLoops::Allow).is_empty());
+    }
+
+    #[test]
+    fn test_repair_makes_graph_valid() {
+        let mut graph: Graph<usize> = Graph::from_edges(
+            false,
+            btreemap! {
+                1 => btreeset! { Edge::value(2).build(), Edge::value(1).build() },
+            },
+        );
+
+        let repaired = repair(&mut graph, SelfLoops::Forbid);
+
+        assert_eq!(repaired.len(), 3);
+        assert!(validate(&graph, SelfLoops::Forbid).is_empty());
+        assert!(graph.contains_edge(&2, &1));
+        assert!(!graph.contains_edge(&1, &1));
+        assert_eq!(graph.in_degree(&1), 1);
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Add, Sub},
+};
+
+/// Числовой тип весов и пропускных способностей рёбер.
+///
+/// Из коробки реализован для `i32`, `i64`, `f32` и `f64`; для собственных
+/// типов (например, рациональных чисел) достаточно реализовать этот трейт.
+pub trait Weight: Copy + Debug + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    /// Значение, которое больше любого достижимого веса пути
+    fn infinity() -> Self;
+
+    /// Сложение, при котором бесконечность остаётся бесконечностью
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Полный порядок, нужный для очередей с приоритетом
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Конечно ли значение. Для точных типов, у которых нет `NaN` и
+    /// бесконечностей, всегда верно.
+    fn is_finite(self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_weight_for_int {
+    ($($t:ty),*) => {$(
+        impl Weight for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn infinity() -> Self {
+                <$t>::MAX
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_weight_for_float {
+    ($($t:ty),*) => {$(
+        impl Weight for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn infinity() -> Self {
+                <$t>::INFINITY
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$t>::total_cmp(self, other)
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_weight_for_int!(i32, i64);
+impl_weight_for_float!(f32, f64);
+
+/// Обёртка, позволяющая класть веса в `BinaryHeap` и сортировать их
+#[derive(Clone, Copy, Debug)]
+pub struct Ordered<W>(pub W);
+
+impl<W: Weight> PartialEq for Ordered<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: Weight> Eq for Ordered<W> {}
+
+impl<W: Weight> PartialOrd for Ordered<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Weight> Ord for Ordered<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Как объединять веса и пропускные способности совпадающих рёбер при
+/// преобразованиях и операциях над графами
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeightPolicy {
+    #[default]
+    Min,
+    Max,
+    Sum,
+    /// Значение из первого (левого) ребра
+    Left,
+    /// Значение из второго (правого) ребра
+    Right,
+}
+
+impl WeightPolicy {
+    pub fn merge<W: Weight>(self, left: W, right: W) -> W {
+        match self {
+            WeightPolicy::Min => {
+                if right.total_cmp(&left).is_lt() {
+                    right
+                } else {
+                    left
+                }
+            }
+            WeightPolicy::Max => {
+                if right.total_cmp(&left).is_gt() {
+                    right
+                } else {
+                    left
+                }
+            }
+            WeightPolicy::Sum => left + right,
+            WeightPolicy::Left => left,
+            WeightPolicy::Right => right,
+        }
+    }
+}